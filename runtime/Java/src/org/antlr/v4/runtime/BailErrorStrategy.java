@@ -48,7 +48,7 @@ public class BailErrorStrategy extends DefaultErrorStrategy {
 			context.exception = e;
 		}
 
-        throw new ParseCancellationException(e);
+        throw recognizer.recordAbort(new ParseCancellationException(e));
     }
 
     /** Make sure we don't attempt to recover inline; if the parser
@@ -63,9 +63,14 @@ public class BailErrorStrategy extends DefaultErrorStrategy {
 			context.exception = e;
 		}
 
-        throw new ParseCancellationException(e);
+        throw recognizer.recordAbort(new ParseCancellationException(e));
     }
 
+	@Override
+	protected String describeRecovery(Parser recognizer, RecognitionException e) {
+		return "cancel the parse";
+	}
+
     /** Make sure we don't attempt to recover from problems in subrules. */
     @Override
     public void sync(Parser recognizer) { }
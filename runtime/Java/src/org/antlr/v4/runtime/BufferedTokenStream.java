@@ -34,6 +34,7 @@ import org.antlr.v4.runtime.misc.Interval;
 import org.antlr.v4.runtime.misc.NotNull;
 
 import java.util.ArrayList;
+import java.util.Collections;
 import java.util.HashSet;
 import java.util.List;
 import java.util.Set;
@@ -79,6 +80,13 @@ public class BufferedTokenStream implements TokenStream {
 	 */
 	protected boolean fetchedEOF;
 
+	/** Minimum number of tokens {@link #sync} pulls from the token source
+	 *  whenever it needs more. The default of 1 lexes strictly on demand;
+	 *  larger values save calls during long lookahead, but are unsafe if
+	 *  the parser changes lexer state (e.g., the mode) from actions.
+	 */
+	protected int fetchSize = 1;
+
     public BufferedTokenStream(TokenSource tokenSource) {
 		if (tokenSource == null) {
 			throw new NullPointerException("tokenSource cannot be null");
@@ -86,9 +94,39 @@ public class BufferedTokenStream implements TokenStream {
         this.tokenSource = tokenSource;
     }
 
+	/** Create a stream over the tokens {@code filled} has already buffered,
+	 *  with its own position. {@code filled} must have fetched EOF, e.g.
+	 *  with {@link #fill}. The token list is shared read-only rather than
+	 *  copied and no more tokens are fetched, so several parsers can each
+	 *  parse a different region or start rule, on different threads,
+	 *  without lexing again.
+	 */
+	public BufferedTokenStream(@NotNull BufferedTokenStream filled) {
+		this(filled.getTokenSource());
+		if ( !filled.fetchedEOF ) {
+			throw new IllegalArgumentException("token stream must be filled first");
+		}
+		tokens = Collections.unmodifiableList(filled.tokens);
+		fetchedEOF = true;
+	}
+
     @Override
     public TokenSource getTokenSource() { return tokenSource; }
 
+	/** Set the minimum number of tokens fetched at a time; see
+	 *  {@link #fetchSize}.
+	 */
+	public void setFetchSize(int fetchSize) {
+		if ( fetchSize<1 ) {
+			throw new IllegalArgumentException("fetch size must be at least 1");
+		}
+		this.fetchSize = fetchSize;
+	}
+
+	public int getFetchSize() {
+		return fetchSize;
+	}
+
 	@Override
 	public int index() { return p; }
 
@@ -139,7 +177,7 @@ public class BufferedTokenStream implements TokenStream {
         int n = i - tokens.size() + 1; // how many more elements we need?
         //System.out.println("sync("+i+") needs "+n);
         if ( n > 0 ) {
-			int fetched = fetch(n);
+			int fetched = fetch(Math.max(n, fetchSize));
 			return fetched >= n;
 		}
 
@@ -58,13 +58,16 @@ public interface CharStream extends IntStream {
 	 * specified {@code interval} lies entirely within a marked range. For more
 	 * information about marked ranges, see {@link IntStream#mark}.
 	 *
+	 * If the end of the stream has been reached, the part of {@code interval}
+	 * at or past the end is ignored, so an interval starting there yields the
+	 * empty string.
+	 *
 	 * @param interval an interval within the stream
 	 * @return the text of the specified interval
 	 *
 	 * @throws NullPointerException if {@code interval} is {@code null}
 	 * @throws IllegalArgumentException if {@code interval.a < 0}, or if
-	 * {@code interval.b < interval.a - 1}, or if {@code interval.b} lies at or
-	 * past the end of the stream
+	 * {@code interval.b < interval.a - 1}
 	 * @throws UnsupportedOperationException if the stream does not support
 	 * getting the text of the specified interval
 	 */
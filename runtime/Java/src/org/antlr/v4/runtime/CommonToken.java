@@ -31,11 +31,18 @@ package org.antlr.v4.runtime;
 
 import org.antlr.v4.runtime.misc.Interval;
 import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Nullable;
 import org.antlr.v4.runtime.misc.Pair;
 
 import java.io.Serializable;
 
 public class CommonToken implements WritableToken, Serializable {
+	/** Source of tokens built without one, so getTokenSource() and
+	 *  getInputStream() return null instead of throwing.
+	 */
+	protected static final Pair<TokenSource, CharStream> EMPTY_SOURCE =
+		new Pair<TokenSource, CharStream>(null, null);
+
 	protected int type;
 	protected int line;
 	protected int charPositionInLine = -1; // set to invalid position
@@ -60,6 +67,7 @@ public class CommonToken implements WritableToken, Serializable {
 
 	public CommonToken(int type) {
 		this.type = type;
+		this.source = EMPTY_SOURCE;
 	}
 
 	public CommonToken(@NotNull Pair<TokenSource, CharStream> source, int type, int channel, int start, int stop) {
@@ -78,6 +86,7 @@ public class CommonToken implements WritableToken, Serializable {
 		this.type = type;
 		this.channel = DEFAULT_CHANNEL;
 		this.text = text;
+		this.source = EMPTY_SOURCE;
 	}
 
 	public CommonToken(Token oldToken) {
@@ -205,9 +214,25 @@ public class CommonToken implements WritableToken, Serializable {
 
 	@Override
 	public String toString() {
+		return toString(null);
+	}
+
+	/** Same as {@link #toString()}, but if {@code r} is a {@link Lexer}
+	 *  that names this token's channel, show the channel by name.
+	 *
+	 *  @see Lexer#getChannelNames
+	 */
+	public String toString(@Nullable Recognizer<?, ?> r) {
 		String channelStr = "";
 		if ( channel>0 ) {
-			channelStr=",channel="+channel;
+			String channelName = null;
+			if ( r instanceof Lexer ) {
+				String[] channelNames = ((Lexer)r).getChannelNames();
+				if ( channelNames!=null && channel<channelNames.length ) {
+					channelName = channelNames[channel];
+				}
+			}
+			channelStr=",channel="+(channelName!=null ? channelName : String.valueOf(channel));
 		}
 		String txt = getText();
 		if ( txt!=null ) {
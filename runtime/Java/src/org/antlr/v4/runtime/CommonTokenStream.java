@@ -60,6 +60,18 @@ public class CommonTokenStream extends BufferedTokenStream {
         this.channel = channel;
     }
 
+	/** Share the tokens {@code filled} has buffered; see
+	 *  {@link BufferedTokenStream#BufferedTokenStream(BufferedTokenStream)}.
+	 */
+	public CommonTokenStream(BufferedTokenStream filled) {
+		super(filled);
+	}
+
+	public CommonTokenStream(BufferedTokenStream filled, int channel) {
+		this(filled);
+		this.channel = channel;
+	}
+
 	@Override
 	protected int adjustSeekIndex(int i) {
 		return nextTokenOnChannel(i, channel);
@@ -40,7 +40,9 @@ import org.antlr.v4.runtime.atn.StarLoopEntryState;
 import org.antlr.v4.runtime.atn.StarLoopbackState;
 import org.antlr.v4.runtime.misc.IntervalSet;
 import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Nullable;
 import org.antlr.v4.runtime.misc.Pair;
+import org.antlr.v4.runtime.misc.ParseCancellationException;
 
 /** This is the default error handling mechanism for ANTLR parsers
  *  and tree parsers.
@@ -65,6 +67,58 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
 
 	protected IntervalSet lastErrorStates;
 
+	/** How many times {@link #recover} has run again at the same
+	 *  {@link #lastErrorIndex} and one of the {@link #lastErrorStates}
+	 *  without the input advancing.
+	 */
+	protected int recoveriesWithoutProgress;
+
+	/** Rule invocation depth of the last {@link #recover}. */
+	protected int lastErrorDepth;
+
+	/** Upper bound on {@link #recoveriesWithoutProgress}. The failsafe in
+	 *  {@link #recover} cannot consume past EOF, so a loop that keeps
+	 *  failing there would otherwise never terminate; past this bound we
+	 *  report the problem and cancel the parse.
+	 */
+	protected int maxRecoveriesWithoutProgress = 1000;
+
+	/** How the error being reported will be recovered from, e.g.
+	 *  "delete ';'"; set only while the error listeners are being
+	 *  notified so listeners can explain it (see
+	 *  {@link ExplainingErrorListener}).
+	 */
+	protected String currentRecovery;
+
+	/** While {@link #reportError} notifies the listeners, the parser and
+	 *  exception to describe the coming {@link #recover} from, on demand.
+	 */
+	protected Parser recoveringParser;
+	protected RecognitionException recoveringFrom;
+
+	/** Set {@link #maxRecoveriesWithoutProgress}; must be at least 1. */
+	public void setMaxRecoveriesWithoutProgress(int maxRecoveriesWithoutProgress) {
+		if ( maxRecoveriesWithoutProgress<1 ) {
+			throw new IllegalArgumentException("max recoveries without progress must be at least 1");
+		}
+		this.maxRecoveriesWithoutProgress = maxRecoveriesWithoutProgress;
+	}
+
+	public int getMaxRecoveriesWithoutProgress() {
+		return maxRecoveriesWithoutProgress;
+	}
+
+	/** Return how the error currently being reported to the listeners
+	 *  will be recovered from, or null outside of such a report.
+	 */
+	@Nullable
+	public String getCurrentRecovery() {
+		if ( currentRecovery==null && recoveringParser!=null ) {
+			currentRecovery = describeRecovery(recoveringParser, recoveringFrom);
+		}
+		return currentRecovery;
+	}
+
 	@Override
 	public void setTokenFactory(TokenFactory<?> factory) {
 		this._factory = factory;
@@ -85,6 +139,8 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
 		errorRecoveryMode = false;
 		lastErrorStates = null;
 		lastErrorIndex = -1;
+		recoveriesWithoutProgress = 0;
+		lastErrorDepth = 0;
 	}
 
 	@Override
@@ -100,21 +156,54 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
 		}
 		recognizer._syntaxErrors++;
 		beginErrorCondition(recognizer);
-		if ( e instanceof NoViableAltException ) {
-			reportNoViableAlternative(recognizer, (NoViableAltException) e);
-		}
-		else if ( e instanceof InputMismatchException ) {
-			reportInputMismatch(recognizer, (InputMismatchException)e);
+		recoveringParser = recognizer;
+		recoveringFrom = e;
+		try {
+			if ( e instanceof NoViableAltException ) {
+				reportNoViableAlternative(recognizer, (NoViableAltException) e);
+			}
+			else if ( e instanceof InputMismatchException ) {
+				reportInputMismatch(recognizer, (InputMismatchException)e);
+			}
+			else if ( e instanceof FailedPredicateException ) {
+				reportFailedPredicate(recognizer, (FailedPredicateException)e);
+			}
+			else {
+				System.err.println("unknown recognition error type: "+e.getClass().getName());
+				if ( recognizer!=null ) {
+					recognizer.notifyErrorListeners(e.getOffendingToken(), e.getMessage(), e);
+				}
+			}
 		}
-		else if ( e instanceof FailedPredicateException ) {
-			reportFailedPredicate(recognizer, (FailedPredicateException)e);
+		finally {
+			currentRecovery = null;
+			recoveringParser = null;
+			recoveringFrom = null;
 		}
-		else {
-			System.err.println("unknown recognition error type: "+e.getClass().getName());
-			if ( recognizer!=null ) {
-				recognizer.notifyErrorListeners(e.getOffendingToken(), e.getMessage(), e);
-			}
+	}
+
+	/** Describe what {@link #recover} will do about {@code e}, which is
+	 *  being reported: resynchronize by consuming tokens until one in
+	 *  {@link #getErrorRecoverySet} is found, after first dropping the
+	 *  current token if recovery is stuck here (see {@link #isStuck}).
+	 */
+	protected String describeRecovery(Parser recognizer, RecognitionException e) {
+		String resync = "resync by consuming until "+
+			getErrorRecoverySet(recognizer).toString(recognizer.getTokenNames());
+		if ( isStuck(recognizer) && recognizer.getInputStream().LA(1)!=Token.EOF ) {
+			return "skip "+getTokenErrorDisplay(recognizer.getCurrentToken())+", then "+resync;
 		}
+		return resync;
+	}
+
+	/** Has {@link #recover} already run at this token index and ATN
+	 *  state? Then the recovery set didn't get us past the error and
+	 *  {@link #recover} drops a token before resynchronizing.
+	 */
+	protected boolean isStuck(Parser recognizer) {
+		return lastErrorIndex==recognizer.getInputStream().index() &&
+			lastErrorStates != null &&
+			lastErrorStates.contains(recognizer.getState());
 	}
 
 	/** Recover from NoViableAlt errors. Also there could be a mismatched
@@ -127,9 +216,17 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
 //						   ", lastErrorIndex="+
 //						   lastErrorIndex+
 //						   ", states="+lastErrorStates);
-		if ( lastErrorIndex==recognizer.getInputStream().index() &&
-			lastErrorStates != null &&
-			lastErrorStates.contains(recognizer.getState()) ) {
+		int depth = recognizer.getContext().depth();
+		if ( isStuck(recognizer) ) {
+			// a repeat at the same (index, state) while unwinding out of
+			// nested invocations of a rule is not a loop; only count the
+			// ones at the same or a deeper invocation level
+			if ( depth>=lastErrorDepth ) {
+				recoveriesWithoutProgress++;
+				if ( recoveriesWithoutProgress > maxRecoveriesWithoutProgress ) {
+					reportNoProgress(recognizer);
+				}
+			}
 			// uh oh, another error at same token index and previously-visited
 			// state in ATN; must be a case where LT(1) is in the recovery
 			// token set so nothing got consumed. Consume a single token
@@ -139,6 +236,10 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
 //			System.err.println("FAILSAFE consumes "+recognizer.getTokenNames()[recognizer.getInputStream().LA(1)]);
 			recognizer.consume();
 		}
+		else if ( lastErrorIndex!=recognizer.getInputStream().index() ) {
+			recoveriesWithoutProgress = 0;
+		}
+		lastErrorDepth = depth;
 		lastErrorIndex = recognizer.getInputStream().index();
 		if ( lastErrorStates==null ) lastErrorStates = new IntervalSet();
 		lastErrorStates.add(recognizer.getState());
@@ -146,6 +247,27 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
 		consumeUntil(recognizer, followSet);
 	}
 
+	/** Error recovery keeps failing at the same token; tell the listeners
+	 *  which rule and token are stuck, then abort the parse rather than
+	 *  loop forever.
+	 *
+	 *  @throws ParseCancellationException always
+	 */
+	protected void reportNoProgress(Parser recognizer) {
+		String ruleName = recognizer.getRuleNames()[recognizer._ctx.getRuleIndex()];
+		Token t = recognizer.getCurrentToken();
+		String msg = "error recovery made no progress in rule "+ruleName+
+			" at "+getTokenErrorDisplay(t);
+		currentRecovery = "cancel the parse";
+		try {
+			recognizer.notifyErrorListeners(t, msg, null);
+		}
+		finally {
+			currentRecovery = null;
+		}
+		throw recognizer.recordAbort(new ParseCancellationException(msg));
+	}
+
 	/** Make sure that the current lookahead symbol is consistent with
 	 *  what were expecting at this point in the ATN.
 	 *
@@ -172,7 +294,7 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
         int la = tokens.LA(1);
 
         // try cheaper subset first; might get lucky. seems to shave a wee bit off
-        if ( recognizer.getATN().nextTokens(s).contains(la) || la==Token.EOF ) return;
+        if ( recognizer.getInterpreter().atn.nextTokens(s).contains(la) || la==Token.EOF ) return;
 
 		// Return but don't end recovery. only do that upon valid token match
 		if (recognizer.isExpectedToken(la)) {
@@ -252,7 +374,13 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
 		IntervalSet expecting = getExpectedTokens(recognizer);
 		String msg = "extraneous input "+tokenName+" expecting "+
 			expecting.toString(recognizer.getTokenNames());
-		recognizer.notifyErrorListeners(t, msg, null);
+		currentRecovery = "delete "+tokenName;
+		try {
+			recognizer.notifyErrorListeners(t, msg, null);
+		}
+		finally {
+			currentRecovery = null;
+		}
 	}
 
 	public void reportMissingToken(Parser recognizer) {
@@ -265,7 +393,15 @@ public class DefaultErrorStrategy implements ANTLRErrorStrategy {
 		String msg = "missing "+expecting.toString(recognizer.getTokenNames())+
 			" at "+getTokenErrorDisplay(t);
 
-		recognizer.notifyErrorListeners(t, msg, null);
+		// getMissingSymbol conjures up the smallest expected token type
+		currentRecovery = "insert "+
+			IntervalSet.of(expecting.getMinElement()).toString(recognizer.getTokenNames());
+		try {
+			recognizer.notifyErrorListeners(t, msg, null);
+		}
+		finally {
+			currentRecovery = null;
+		}
 	}
 
 	/** Attempt to recover from a single missing or extra token.
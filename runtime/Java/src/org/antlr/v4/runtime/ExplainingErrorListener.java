@@ -0,0 +1,179 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime;
+
+import org.antlr.v4.runtime.misc.IntervalSet;
+import org.antlr.v4.runtime.misc.Interval;
+import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Nullable;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/** Print a multi-line explanation of each syntax error to stderr: the
+ *  usual "line L:C msg" header followed by the offending source line with
+ *  a caret under the error position and, for parser errors, the rule
+ *  invocation stack, the expected tokens split into literals and named
+ *  tokens, and how the parser recovers, as told by a
+ *  {@link DefaultErrorStrategy} (see
+ *  {@link DefaultErrorStrategy#getCurrentRecovery}).
+ */
+public class ExplainingErrorListener extends BaseErrorListener {
+	public static final ExplainingErrorListener INSTANCE = new ExplainingErrorListener();
+
+	protected static final int MAX_LINE_LOOKAHEAD = 256;
+
+	@Override
+	public void syntaxError(Recognizer<?, ?> recognizer,
+							Object offendingSymbol,
+							int line,
+							int charPositionInLine,
+							String msg,
+							RecognitionException e)
+	{
+		System.err.print(explain(recognizer, offendingSymbol, line, charPositionInLine, msg));
+	}
+
+	/** Render the explanation for a single syntax error as reported to
+	 *  {@link ANTLRErrorListener#syntaxError}. Each line of the result,
+	 *  including the last, ends with a newline.
+	 */
+	@NotNull
+	public static String explain(@NotNull Recognizer<?, ?> recognizer,
+								 @Nullable Object offendingSymbol,
+								 int line,
+								 int charPositionInLine,
+								 String msg)
+	{
+		StringBuilder buf = new StringBuilder();
+		buf.append("line ").append(line).append(':').append(charPositionInLine);
+		buf.append(' ').append(msg).append('\n');
+
+		CharStream input = null;
+		int index = -1;
+		if ( offendingSymbol instanceof Token ) {
+			Token t = (Token)offendingSymbol;
+			input = t.getInputStream();
+			index = t.getStartIndex();
+		}
+		else if ( recognizer instanceof Lexer ) {
+			Lexer lexer = (Lexer)recognizer;
+			input = lexer.getInputStream();
+			index = lexer._tokenStartCharIndex;
+		}
+
+		String sourceLine = getSourceLine(input, index, charPositionInLine);
+		if ( sourceLine!=null ) {
+			buf.append(sourceLine).append('\n');
+			for (int i = 0; i < charPositionInLine; i++) {
+				buf.append(sourceLine.charAt(i)=='\t' ? '\t' : ' ');
+			}
+			buf.append("^\n");
+		}
+
+		if ( recognizer instanceof Parser ) {
+			Parser parser = (Parser)recognizer;
+			buf.append("rule stack: ").append(parser.getRuleInvocationStack()).append('\n');
+
+			IntervalSet expected = parser.getExpectedTokens();
+			String[] tokenNames = parser.getTokenNames();
+			List<String> literals = new ArrayList<String>();
+			List<String> named = new ArrayList<String>();
+			for (int ttype : expected.toArray()) {
+				String name;
+				if ( ttype==Token.EOF ) name = "<EOF>";
+				else if ( ttype>=0 && ttype<tokenNames.length ) name = tokenNames[ttype];
+				else name = String.valueOf(ttype);
+
+				if ( name.startsWith("'") ) literals.add(name);
+				else named.add(name);
+			}
+
+			if ( !literals.isEmpty() ) {
+				buf.append("expected literals: ").append(join(literals)).append('\n');
+			}
+			if ( !named.isEmpty() ) {
+				buf.append("expected tokens: ").append(join(named)).append('\n');
+			}
+
+			ANTLRErrorStrategy handler = parser.getErrorHandler();
+			if ( handler instanceof DefaultErrorStrategy ) {
+				String recovery = ((DefaultErrorStrategy)handler).getCurrentRecovery();
+				if ( recovery!=null ) buf.append("recovery: ").append(recovery).append('\n');
+			}
+		}
+
+		return buf.toString();
+	}
+
+	/** Return the line of {@code input} containing character {@code index},
+	 *  or {@code null} if it is not available. The line is assumed to start
+	 *  {@code charPositionInLine} characters before {@code index}.
+	 */
+	@Nullable
+	protected static String getSourceLine(@Nullable CharStream input, int index, int charPositionInLine) {
+		if ( input==null || index<0 || charPositionInLine<0 ) return null;
+
+		int start = index - charPositionInLine;
+		if ( start<0 ) return null;
+
+		String text;
+		try {
+			// only look a bounded distance past the error for the end of line
+			int stop = Math.min(input.size() - 1, index + MAX_LINE_LOOKAHEAD);
+			if ( start>stop+1 ) return null;
+			text = start<=stop ? input.getText(Interval.of(start, stop)) : "";
+		}
+		catch (UnsupportedOperationException e) {
+			// unbuffered streams don't know their size or keep old text
+			return null;
+		}
+
+		for (int i = 0; i < text.length(); i++) {
+			char c = text.charAt(i);
+			if ( c=='\n' || c=='\r' ) {
+				text = text.substring(0, i);
+				break;
+			}
+		}
+
+		if ( text.length()<charPositionInLine ) return null;
+		return text;
+	}
+
+	private static String join(List<String> names) {
+		StringBuilder buf = new StringBuilder();
+		for (int i = 0; i < names.size(); i++) {
+			if ( i>0 ) buf.append(", ");
+			buf.append(names.get(i));
+		}
+		return buf.toString();
+	}
+}
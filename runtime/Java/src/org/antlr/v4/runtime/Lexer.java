@@ -32,10 +32,11 @@ package org.antlr.v4.runtime;
 import org.antlr.v4.runtime.atn.LexerATNSimulator;
 import org.antlr.v4.runtime.misc.IntegerStack;
 import org.antlr.v4.runtime.misc.Interval;
+import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Nullable;
 import org.antlr.v4.runtime.misc.Pair;
 
 import java.util.ArrayList;
-import java.util.EmptyStackException;
 import java.util.List;
 
 /** A lexer is recognizer that draws input symbols from a character stream.
@@ -46,12 +47,18 @@ import java.util.List;
 public abstract class Lexer extends Recognizer<Integer, LexerATNSimulator>
 	implements TokenSource
 {
+	/** A look ahead from the current char, for {@link Lexer#speculate} */
+	public interface Speculation {
+		boolean matches(@NotNull CharStream input);
+	}
+
 	public static final int DEFAULT_MODE = 0;
 	public static final int MORE = -2;
 	public static final int SKIP = -3;
 
 	public static final int DEFAULT_TOKEN_CHANNEL = Token.DEFAULT_CHANNEL;
 	public static final int HIDDEN = Token.HIDDEN_CHANNEL;
+	public static final int MIN_USER_CHANNEL_VALUE = Token.MIN_USER_CHANNEL_VALUE;
 	public static final int MIN_CHAR_VALUE = '\u0000';
 	public static final int MAX_CHAR_VALUE = '\uFFFE';
 
@@ -102,6 +109,9 @@ public abstract class Lexer extends Recognizer<Integer, LexerATNSimulator>
 	 */
 	public String _text;
 
+	/** Print each token match to stdout; see {@link #setTrace}. */
+	protected boolean _trace;
+
 	public Lexer() { }
 
 	public Lexer(CharStream input) {
@@ -160,19 +170,36 @@ public abstract class Lexer extends Recognizer<Integer, LexerATNSimulator>
 //				System.out.println("nextToken line "+tokenStartLine+" at "+((char)input.LA(1))+
 //								   " in mode "+mode+
 //								   " at index "+input.index());
+					if ( !isDefinedMode(_mode) ) {
+						notifyListeners("no such lexer mode: "+_mode);
+						_mode = DEFAULT_MODE;
+					}
+					int matchStartIndex = _input.index();
+					int matchMode = _mode;
 					int ttype;
+					LexerNoViableAltException error = null;
 					try {
 						ttype = getInterpreter().match(_input, _mode);
 					}
 					catch (LexerNoViableAltException e) {
+						error = e;
 						notifyListeners(e);		// report error
 						recover(e);
 						ttype = SKIP;
 					}
+					if ( _type == Token.INVALID_TYPE ) _type = ttype;
+					if ( _trace ) traceMatch(matchMode, matchStartIndex, ttype, error);
+					if ( (_type==SKIP || _type==MORE) &&
+						 _input.index()==matchStartIndex &&
+						 _input.LA(1)!=IntStream.EOF )
+					{
+						// nothing consumed; we'd match the same empty input forever
+						notifyListeners("lexer made no progress at: "+getCharErrorDisplay(_input.LA(1)));
+						getInterpreter().consume(_input);
+					}
 					if ( _input.LA(1)==IntStream.EOF ) {
 						_hitEOF = true;
 					}
-					if ( _type == Token.INVALID_TYPE ) _type = ttype;
 					if ( _type ==SKIP ) {
 						continue outer;
 					}
@@ -212,8 +239,15 @@ public abstract class Lexer extends Recognizer<Integer, LexerATNSimulator>
 		mode(m);
 	}
 
+	/** Return to the mode saved by the last {@link #pushMode}. Popping an
+	 *  empty mode stack is reported to the error listeners and leaves the
+	 *  current mode unchanged.
+	 */
 	public int popMode() {
-		if ( _modeStack.isEmpty() ) throw new EmptyStackException();
+		if ( _modeStack.isEmpty() ) {
+			notifyListeners("popMode with empty mode stack");
+			return _mode;
+		}
 		if ( LexerATNSimulator.debug ) System.out.println("popMode back to "+ _modeStack.peek());
 		mode( _modeStack.pop() );
 		return _mode;
@@ -309,6 +343,24 @@ public abstract class Lexer extends Recognizer<Integer, LexerATNSimulator>
 		return _input.index();
 	}
 
+	/** Run {@code s} on the char stream, which it may consume from freely,
+	 *  then rewind the stream to where it was, even if {@code s} throws.
+	 *  Use this in actions and predicates that must look ahead an arbitrary
+	 *  distance. Consuming directly from the stream does not update
+	 *  line or column, so there is nothing else to restore.
+	 */
+	public boolean speculate(@NotNull Speculation s) {
+		int index = _input.index();
+		int marker = _input.mark();
+		try {
+			return s.matches(_input);
+		}
+		finally {
+			_input.seek(index);
+			_input.release(marker);
+		}
+	}
+
 	/** Return the text matched so far for the current token or any
 	 *  text override.
 	 */
@@ -349,10 +401,68 @@ public abstract class Lexer extends Recognizer<Integer, LexerATNSimulator>
 		return _channel;
 	}
 
+	/** During development, print a line to stdout for every token match
+	 *  attempt: the mode, start index, lexer rule that matched, the action
+	 *  it executed (if any) and the resulting token type and channel.
+	 */
+	public void setTrace(boolean trace) {
+		_trace = trace;
+	}
+
+	public boolean getTrace() {
+		return _trace;
+	}
+
+	/** Print one trace line. If the match failed, {@code error} is the
+	 *  exception; the interpreter may still hold an accept state from a
+	 *  prefix it matched before failing, so don't ask it for the rule.
+	 */
+	protected void traceMatch(int mode, int startIndex, int ttype,
+							  @Nullable LexerNoViableAltException error)
+	{
+		StringBuilder buf = new StringBuilder();
+		buf.append("match mode=").append(getName(getModeNames(), mode));
+		buf.append(" start=").append(startIndex);
+		int ruleIndex = error!=null ? -1 : getInterpreter().getMatchedRuleIndex();
+		if ( ttype==Token.EOF ) {
+			buf.append(" -> EOF");
+		}
+		else if ( ruleIndex<0 ) {
+			buf.append(" -> no viable token");
+		}
+		else {
+			buf.append(" rule=").append(getName(getRuleNames(), ruleIndex));
+			int actionIndex = getInterpreter().getMatchedActionIndex();
+			if ( actionIndex>=0 ) buf.append(" action=").append(actionIndex);
+			buf.append(" -> ");
+			if ( _type==SKIP ) buf.append("skip");
+			else if ( _type==MORE ) buf.append("more");
+			else {
+				buf.append(getName(getTokenNames(), _type));
+				buf.append(" channel=").append(getName(getChannelNames(), _channel));
+			}
+		}
+		System.out.println(buf);
+	}
+
+	protected static String getName(String[] names, int i) {
+		if ( names!=null && i>=0 && i<names.length ) return names[i];
+		return String.valueOf(i);
+	}
+
 	public String[] getModeNames() {
 		return null;
 	}
 
+	/** Used to print out channel names like HIDDEN during debugging. Element
+	 *  i is the name of channel i. Lexers that send tokens to channels at or
+	 *  above {@link #MIN_USER_CHANNEL_VALUE} should override this to name
+	 *  them too.
+	 */
+	public String[] getChannelNames() {
+		return new String[] {"DEFAULT_TOKEN_CHANNEL", "HIDDEN"};
+	}
+
 	/** Used to print out token names like ID during debugging and
 	 *  error reporting.  The generated parsers implement a method
 	 *  that overrides this to point to their String[] tokenNames.
@@ -376,6 +486,9 @@ public abstract class Lexer extends Recognizer<Integer, LexerATNSimulator>
 	}
 
 	public void recover(LexerNoViableAltException e) {
+		if ( e instanceof LexerScanLimitException ) {
+			return; // chars were scanned, so we've made progress already
+		}
 		if (_input.LA(1) != IntStream.EOF) {
 			// skip a char and try again
 			getInterpreter().consume(_input);
@@ -383,13 +496,32 @@ public abstract class Lexer extends Recognizer<Integer, LexerATNSimulator>
 	}
 
 	public void notifyListeners(LexerNoViableAltException e) {
-		String text = _input.getText(Interval.of(_tokenStartCharIndex, _input.index()));
+		int stop = _input.index();
+		if ( e instanceof LexerScanLimitException ) {
+			stop--; // the char at index wasn't scanned
+		}
+		String text = _input.getText(Interval.of(_tokenStartCharIndex, stop));
 		String msg = "token recognition error at: '"+ getErrorDisplay(text) + "'";
 
 		ANTLRErrorListener listener = getErrorListenerDispatch();
 		listener.syntaxError(this, null, _tokenStartLine, _tokenStartCharPositionInLine, msg, e);
 	}
 
+	/** Report a problem with the lexer's own state, such as a bad mode
+	 *  switch in an action, at the start of the current token.
+	 */
+	public void notifyListeners(String msg) {
+		ANTLRErrorListener listener = getErrorListenerDispatch();
+		listener.syntaxError(this, null, _tokenStartLine, _tokenStartCharPositionInLine, msg, null);
+	}
+
+	/** Is {@code m} one of the modes in this lexer's ATN? */
+	public boolean isDefinedMode(int m) {
+		LexerATNSimulator interp = getInterpreter();
+		if ( interp==null ) return true; // nothing to check against
+		return m >= 0 && m < interp.atn.modeToStartState.size();
+	}
+
 	public String getErrorDisplay(String s) {
 		StringBuilder buf = new StringBuilder();
 		for (char c : s.toCharArray()) {
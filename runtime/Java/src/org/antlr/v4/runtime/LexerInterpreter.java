@@ -0,0 +1,142 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime;
+
+import org.antlr.v4.runtime.atn.ATN;
+import org.antlr.v4.runtime.atn.ATNSimulator;
+import org.antlr.v4.runtime.atn.LexerATNSimulator;
+import org.antlr.v4.runtime.atn.PredictionContextCache;
+import org.antlr.v4.runtime.dfa.DFA;
+import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Nullable;
+
+/** A lexer driven directly by a grammar's ATN, for tools (grammar IDEs,
+ *  REPLs) that have a grammar's serialized ATN and names but no
+ *  generated lexer class. Embedded actions and lexer commands such as
+ *  {@code -> skip} compile to code in the generated lexer, so they are
+ *  not executed here; predicates are assumed true.
+ */
+public class LexerInterpreter extends Lexer {
+	protected final String grammarFileName;
+	/** The ATN as given to the constructor, if it was given serialized. */
+	protected final String serializedATN;
+	protected final ATN atn;
+
+	protected final String[] tokenNames;
+	protected final String[] ruleNames;
+	protected final String[] modeNames;
+	protected final String[] channelNames;
+
+	protected final DFA[] _decisionToDFA;
+	protected final PredictionContextCache _sharedContextCache =
+		new PredictionContextCache();
+
+	public LexerInterpreter(String grammarFileName,
+							@NotNull String serializedATN,
+							String[] tokenNames, String[] ruleNames,
+							String[] modeNames, @Nullable String[] channelNames,
+							CharStream input)
+	{
+		this(grammarFileName, serializedATN,
+			 ATNSimulator.deserialize(serializedATN.toCharArray()),
+			 tokenNames, ruleNames, modeNames, channelNames, input);
+	}
+
+	public LexerInterpreter(String grammarFileName, @NotNull ATN atn,
+							String[] tokenNames, String[] ruleNames,
+							String[] modeNames, @Nullable String[] channelNames,
+							CharStream input)
+	{
+		this(grammarFileName, null, atn, tokenNames, ruleNames, modeNames,
+			 channelNames, input);
+	}
+
+	protected LexerInterpreter(String grammarFileName,
+							   @Nullable String serializedATN, @NotNull ATN atn,
+							   String[] tokenNames, String[] ruleNames,
+							   String[] modeNames, @Nullable String[] channelNames,
+							   CharStream input)
+	{
+		super(input);
+		if ( atn.grammarType!=ATN.LEXER ) {
+			throw new IllegalArgumentException("The ATN must be a lexer ATN.");
+		}
+		this.grammarFileName = grammarFileName;
+		this.serializedATN = serializedATN;
+		this.atn = atn;
+		this.tokenNames = tokenNames;
+		this.ruleNames = ruleNames;
+		this.modeNames = modeNames;
+		this.channelNames = channelNames;
+		this._decisionToDFA = new DFA[atn.getNumberOfDecisions()];
+		this._interp = new LexerATNSimulator(this, atn, _decisionToDFA, _sharedContextCache);
+	}
+
+	@Override
+	public ATN getATN() {
+		return atn;
+	}
+
+	/** The serialized ATN, if this interpreter was built from one;
+	 *  otherwise there is nothing to return and this throws like
+	 *  {@link Recognizer#getSerializedATN}.
+	 */
+	@Override
+	public String getSerializedATN() {
+		if ( serializedATN!=null ) return serializedATN;
+		return super.getSerializedATN();
+	}
+
+	@Override
+	public String getGrammarFileName() {
+		return grammarFileName;
+	}
+
+	@Override
+	public String[] getTokenNames() {
+		return tokenNames;
+	}
+
+	@Override
+	public String[] getRuleNames() {
+		return ruleNames;
+	}
+
+	@Override
+	public String[] getModeNames() {
+		return modeNames;
+	}
+
+	@Override
+	public String[] getChannelNames() {
+		if ( channelNames!=null ) return channelNames;
+		return super.getChannelNames();
+	}
+}
@@ -0,0 +1,152 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime;
+
+import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Pair;
+
+import java.util.List;
+
+/** A token source that replays a list of tokens, for feeding a parser
+ *  tokens from somewhere other than a lexer (tests, preprocessors, a
+ *  saved token list). If the last token is EOF, it is returned for every
+ *  call to nextToken() past the end of the list; otherwise an EOF token
+ *  is created just after the last token.
+ */
+public class ListTokenSource implements TokenSource {
+	@NotNull
+	protected final List<? extends Token> tokens;
+
+	/** Name from the constructor, or null to ask the tokens' input stream */
+	protected final String sourceName;
+
+	/** Index of the next token nextToken() returns */
+	protected int i;
+
+	protected Token eofToken;
+
+	protected TokenFactory<?> _factory = CommonTokenFactory.DEFAULT;
+
+	public ListTokenSource(@NotNull List<? extends Token> tokens) {
+		this(tokens, null);
+	}
+
+	public ListTokenSource(@NotNull List<? extends Token> tokens, String sourceName) {
+		if ( tokens==null ) {
+			throw new NullPointerException("tokens cannot be null");
+		}
+		this.tokens = tokens;
+		this.sourceName = sourceName;
+	}
+
+	@Override
+	public Token nextToken() {
+		if ( i >= tokens.size() ) {
+			if ( eofToken==null ) {
+				int start = -1;
+				if ( !tokens.isEmpty() ) {
+					int previousStop = tokens.get(tokens.size()-1).getStopIndex();
+					if ( previousStop!=-1 ) start = previousStop + 1;
+				}
+				int stop = Math.max(-1, start - 1);
+				eofToken = _factory.create(new Pair<TokenSource, CharStream>(this, getInputStream()),
+										   Token.EOF, "EOF", Token.DEFAULT_CHANNEL,
+										   start, stop, getLine(), getCharPositionInLine());
+			}
+			return eofToken;
+		}
+
+		Token t = tokens.get(i);
+		if ( i==tokens.size()-1 && t.getType()==Token.EOF ) eofToken = t;
+		i++;
+		return t;
+	}
+
+	@Override
+	public int getLine() {
+		if ( i < tokens.size() ) return tokens.get(i).getLine();
+		if ( eofToken!=null ) return eofToken.getLine();
+		if ( tokens.isEmpty() ) return 1;
+
+		// line just past the last token
+		Token last = tokens.get(tokens.size()-1);
+		int line = last.getLine();
+		String text = last.getText();
+		if ( text!=null ) {
+			for (int j=0; j<text.length(); j++) {
+				if ( text.charAt(j)=='\n' ) line++;
+			}
+		}
+		return line;
+	}
+
+	@Override
+	public int getCharPositionInLine() {
+		if ( i < tokens.size() ) return tokens.get(i).getCharPositionInLine();
+		if ( eofToken!=null ) return eofToken.getCharPositionInLine();
+		if ( tokens.isEmpty() ) return 0;
+
+		// column just past the last token
+		Token last = tokens.get(tokens.size()-1);
+		String text = last.getText();
+		if ( text!=null ) {
+			int lastNewLine = text.lastIndexOf('\n');
+			if ( lastNewLine>=0 ) return text.length() - lastNewLine - 1;
+		}
+		return last.getCharPositionInLine() + last.getStopIndex() - last.getStartIndex() + 1;
+	}
+
+	@Override
+	public CharStream getInputStream() {
+		if ( i < tokens.size() ) return tokens.get(i).getInputStream();
+		if ( eofToken!=null ) return eofToken.getInputStream();
+		if ( !tokens.isEmpty() ) return tokens.get(tokens.size()-1).getInputStream();
+		return null;
+	}
+
+	@Override
+	public String getSourceName() {
+		if ( sourceName!=null ) return sourceName;
+		CharStream input = getInputStream();
+		if ( input!=null ) return input.getSourceName();
+		return "List";
+	}
+
+	@Override
+	public void setTokenFactory(@NotNull TokenFactory<?> factory) {
+		this._factory = factory;
+	}
+
+	@Override
+	@NotNull
+	public TokenFactory<?> getTokenFactory() {
+		return _factory;
+	}
+}
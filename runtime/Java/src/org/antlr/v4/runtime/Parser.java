@@ -33,16 +33,21 @@ import org.antlr.v4.runtime.atn.ATN;
 import org.antlr.v4.runtime.atn.ATNSimulator;
 import org.antlr.v4.runtime.atn.ATNState;
 import org.antlr.v4.runtime.atn.ParserATNSimulator;
+import org.antlr.v4.runtime.atn.PredictionContextCache;
 import org.antlr.v4.runtime.atn.RuleTransition;
 import org.antlr.v4.runtime.dfa.DFA;
+import org.antlr.v4.runtime.misc.IntegerStack;
 import org.antlr.v4.runtime.misc.IntervalSet;
+import org.antlr.v4.runtime.misc.NotNull;
 import org.antlr.v4.runtime.misc.Nullable;
 import org.antlr.v4.runtime.tree.ErrorNode;
+import org.antlr.v4.runtime.tree.ErrorNodeImpl;
 import org.antlr.v4.runtime.tree.ParseTreeListener;
 import org.antlr.v4.runtime.tree.ParseTreeWalker;
 import org.antlr.v4.runtime.tree.TerminalNode;
 
 import java.util.ArrayList;
+import java.util.Collections;
 import java.util.List;
 
 /** This is all the parsing support code essentially; most of it is error recovery stuff. */
@@ -92,6 +97,31 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		}
 	}
 
+	/** A syntax error as reported to the error listeners; see
+	 *  {@link #setRecordErrors}.
+	 */
+	public static class ReportedError {
+		@NotNull
+		public final Token offendingToken;
+		public final String msg;
+		@Nullable
+		public final RecognitionException e;
+
+		public ReportedError(@NotNull Token offendingToken, String msg,
+							 @Nullable RecognitionException e)
+		{
+			this.offendingToken = offendingToken;
+			this.msg = msg;
+			this.e = e;
+		}
+
+		@Override
+		public String toString() {
+			return "line "+offendingToken.getLine()+":"+
+				offendingToken.getCharPositionInLine()+" "+msg;
+		}
+	}
+
 	protected ANTLRErrorStrategy _errHandler = new DefaultErrorStrategy();
 
 	protected TokenStream _input;
@@ -103,8 +133,42 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 	 */
 	protected ParserRuleContext _ctx;
 
+	/** The context of the outermost rule invoked since the last reset.
+	 *  Unlike _ctx, this survives the rule returning, so the partial tree
+	 *  is still reachable after a parse aborts with an exception.
+	 */
+	protected ParserRuleContext _rootCtx;
+
+	/** Why the parse since the last reset stopped early, if an error
+	 *  strategy aborted it; see {@link #recordAbort}.
+	 */
+	protected RuntimeException _abortException;
+
+	/** Keep the errors passed to {@link #notifyErrorListeners}? Off by
+	 *  default; listeners already see every error.
+	 */
+	protected boolean _recordErrors = false;
+
+	/** The errors reported since the last reset while
+	 *  {@link #_recordErrors} was on.
+	 */
+	protected final List<ReportedError> _reportedErrors = new ArrayList<ReportedError>();
+
+	/** Precedence of each left-recursive rule invocation on the stack;
+	 *  the bottom entry 0 stands for "not inside a left-recursive rule".
+	 */
+	protected final IntegerStack _precedenceStack;
+	{
+		_precedenceStack = new IntegerStack();
+		_precedenceStack.push(0);
+	}
+
 	protected boolean _buildParseTrees = true;
 
+	/** Applied to each error node added to the parse tree. */
+	@NotNull
+	protected ErrorNodeImpl.TextMode _errorNodeTextMode = ErrorNodeImpl.TextMode.TOKEN;
+
 	protected TraceListener _tracer;
 
 	/** If the listener is non-null, trigger enter and exit rule events
@@ -129,8 +193,13 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		if ( getInputStream()!=null ) getInputStream().seek(0);
 		_errHandler.endErrorCondition(this);
 		_ctx = null;
+		_rootCtx = null;
+		_abortException = null;
+		_reportedErrors.clear();
 		_syntaxErrors = 0;
 		_tracer = null;
+		_precedenceStack.clear();
+		_precedenceStack.push(0);
 		ATNSimulator interpreter = getInterpreter();
 		if (interpreter != null) {
 			interpreter.reset();
@@ -152,7 +221,7 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 			if ( _buildParseTrees && t.getTokenIndex()==-1 ) {
 				// we must have conjured up a new token during single token insertion
 				// if it's not the current symbol
-				_ctx.addErrorNode(t);
+				addErrorNode(t);
 			}
 		}
 		return t;
@@ -169,7 +238,7 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 			if (_buildParseTrees && t.getTokenIndex() == -1) {
 				// we must have conjured up a new token during single token insertion
 				// if it's not the current symbol
-				_ctx.addErrorNode(t);
+				addErrorNode(t);
 			}
 		}
 
@@ -204,6 +273,28 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		return _buildParseTrees;
 	}
 
+	/** Choose what {@link ErrorNode#getText} returns for error nodes the
+	 *  parser adds from now on: the token text (the default), an
+	 *  {@code <error: text>} marker, or the empty string. Rule context
+	 *  text is built from its children, so it follows the same choice.
+	 */
+	public void setErrorNodeTextMode(@NotNull ErrorNodeImpl.TextMode mode) {
+		_errorNodeTextMode = mode;
+	}
+
+	@NotNull
+	public ErrorNodeImpl.TextMode getErrorNodeTextMode() {
+		return _errorNodeTextMode;
+	}
+
+	protected ErrorNode addErrorNode(Token badToken) {
+		ErrorNode node = _ctx.addErrorNode(badToken);
+		if ( node instanceof ErrorNodeImpl ) {
+			((ErrorNodeImpl)node).textMode = _errorNodeTextMode;
+		}
+		return node;
+	}
+
 	/**
 	 * Trim the internal lists of the parse tree during parsing to conserve memory.
 	 * This property is set to {@code false} by default for a newly constructed parser.
@@ -362,6 +453,7 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		int charPositionInLine = -1;
 		line = offendingToken.getLine();
 		charPositionInLine = offendingToken.getCharPositionInLine();
+		if ( _recordErrors ) _reportedErrors.add(new ReportedError(offendingToken, msg, e));
 
 		ANTLRErrorListener listener = getErrorListenerDispatch();
 		listener.syntaxError(this, offendingToken, line, charPositionInLine, msg, e);
@@ -388,7 +480,7 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		boolean hasListener = _parseListeners != null && !_parseListeners.isEmpty();
 		if (_buildParseTrees || hasListener) {
 			if ( _errHandler.inErrorRecoveryMode(this) ) {
-				ErrorNode node = _ctx.addErrorNode(o);
+				ErrorNode node = addErrorNode(o);
 				if (_parseListeners != null) {
 					for (ParseTreeListener listener : _parseListeners) {
 						listener.visitErrorNode(node);
@@ -423,8 +515,10 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 	 *  to get trace facilities.
 	 */
 	public void enterRule(ParserRuleContext localctx, int state, int ruleIndex) {
+		checkNotPruned(ruleIndex);
 		setState(state);
 		_ctx = localctx;
+		if ( _ctx.parent==null ) _rootCtx = _ctx;
 		_ctx.start = _input.LT(1);
 		if (_buildParseTrees) addContextToParseTree();
         if ( _parseListeners != null) triggerEnterRuleEvent();
@@ -438,6 +532,37 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		_ctx = (ParserRuleContext)_ctx.parent;
     }
 
+	/** Rules removed by {@link ATN#pruneUnreachableRules} have no states
+	 *  left to parse with; fail clearly instead of deep in prediction.
+	 */
+	protected void checkNotPruned(int ruleIndex) {
+		ParserATNSimulator interp = getInterpreter();
+		if ( interp==null || interp.atn==null || !interp.atn.hasPrunedRules ) return;
+		if ( interp.atn.ruleToStartState[ruleIndex]==null ) {
+			throw new IllegalStateException("rule "+getRuleNames()[ruleIndex]+
+											" was pruned from the ATN");
+		}
+	}
+
+	/** Parse with a private copy of the grammar's ATN, deserialized from
+	 *  {@link #getSerializedATN}, from which the rules not reachable from
+	 *  {@code startRules} are removed (see
+	 *  {@link ATN#pruneUnreachableRules}). This parser gets a new
+	 *  interpreter and DFA cache for it; other parsers of the grammar
+	 *  keep using the shared ATN. {@link #getATN} still returns the
+	 *  shared one.
+	 *
+	 *  @return the number of states removed
+	 */
+	public int pruneUnreachableRules(int... startRules) {
+		ATN atn = ATNSimulator.deserialize(getSerializedATN().toCharArray());
+		int removed = atn.pruneUnreachableRules(startRules);
+		setInterpreter(new ParserATNSimulator(this, atn,
+											  new DFA[atn.getNumberOfDecisions()],
+											  new PredictionContextCache()));
+		return removed;
+	}
+
 	public void enterOuterAlt(ParserRuleContext localctx, int altNum) {
 		// if we have new localctx, make sure we replace existing ctx
 		// that is previous child of parse tree
@@ -448,12 +573,20 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 				parent.addChild(localctx);
 			}
 		}
+		if ( _rootCtx==_ctx ) _rootCtx = localctx;
 		_ctx = localctx;
 		_ctx.altNum = altNum;
 	}
 
 	public void enterRecursionRule(ParserRuleContext localctx, int ruleIndex) {
+		enterRecursionRule(localctx, ruleIndex, 0);
+	}
+
+	public void enterRecursionRule(ParserRuleContext localctx, int ruleIndex, int precedence) {
+		checkNotPruned(ruleIndex);
+		_precedenceStack.push(precedence);
 		_ctx = localctx;
+		if ( _ctx.parent==null ) _rootCtx = _ctx;
 		_ctx.start = _input.LT(1);
 		if (_parseListeners != null) {
 			triggerEnterRuleEvent(); // simulates rule entry for left-recursive rules
@@ -467,6 +600,7 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		previous.invokingState = state;
 		previous.stop = _input.LT(-1);
 
+		if ( _rootCtx==previous ) _rootCtx = localctx;
 		_ctx = localctx;
 		_ctx.start = previous.start;
 		if (_buildParseTrees) {
@@ -479,6 +613,7 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 	}
 
 	public void unrollRecursionContexts(ParserRuleContext _parentctx) {
+		_precedenceStack.pop();
 		_ctx.stop = _input.LT(-1);
 		ParserRuleContext retctx = _ctx; // save current ctx (return value)
 
@@ -494,7 +629,21 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		}
 		// hook into tree
 		retctx.parent = _parentctx;
-		if (_buildParseTrees) _parentctx.addChild(retctx); // add return ctx into invoking rule's tree
+		// a left-recursive start rule has no invoking rule
+		if (_buildParseTrees && _parentctx != null) _parentctx.addChild(retctx); // add return ctx into invoking rule's tree
+	}
+
+	/** Get the precedence level of the innermost left-recursive rule
+	 *  invocation, or -1 if no left-recursive rule is active.
+	 */
+	public int getPrecedence() {
+		if ( _precedenceStack.size()<=1 ) return -1; // just the bottom 0
+		return _precedenceStack.peek();
+	}
+
+	@Override
+	public boolean precpred(RuleContext localctx, int precedence) {
+		return precedence >= _precedenceStack.peek();
 	}
 
 	public ParserRuleContext getInvokingContext(int ruleIndex) {
@@ -510,6 +659,60 @@ public abstract class Parser extends Recognizer<Token, ParserATNSimulator> {
 		return _ctx;
 	}
 
+	/** Return the context of the outermost rule invoked since the last
+	 *  {@link #reset}, or null if no rule has been invoked. If the parse
+	 *  was aborted (e.g., {@link BailErrorStrategy} threw a
+	 *  {@link org.antlr.v4.runtime.misc.ParseCancellationException}), this
+	 *  is the tree built up to the failure point; stop tokens are set for
+	 *  every rule that was unwound. {@link #getAbortException} says why it
+	 *  stopped and {@link #getReportedErrors} lists the errors reported
+	 *  before that.
+	 */
+	public ParserRuleContext getRootContext() {
+		return _rootCtx;
+	}
+
+	/** Return the exception an error strategy aborted the parse with
+	 *  since the last {@link #reset}, or null if it was not aborted.
+	 *  Only aborts that go through {@link #recordAbort} are seen; the
+	 *  parser cannot observe anything else that unwinds its rules, such
+	 *  as an exception thrown by an action or a StackOverflowError from
+	 *  input nested too deeply, so those reach the caller unrecorded.
+	 */
+	@Nullable
+	public RuntimeException getAbortException() {
+		return _abortException;
+	}
+
+	/** Note that the parse is being aborted with {@code e}, for
+	 *  {@link #getAbortException}, and return it so callers can
+	 *  {@code throw recognizer.recordAbort(...)}.
+	 */
+	public <T extends RuntimeException> T recordAbort(T e) {
+		_abortException = e;
+		return e;
+	}
+
+	/** Keep every error reported to the listeners from now on, for
+	 *  {@link #getReportedErrors}.
+	 */
+	public void setRecordErrors(boolean recordErrors) {
+		this._recordErrors = recordErrors;
+	}
+
+	public boolean getRecordErrors() {
+		return _recordErrors;
+	}
+
+	/** Return the errors reported to the listeners since the last
+	 *  {@link #reset}, in order. Empty unless {@link #setRecordErrors}
+	 *  was turned on.
+	 */
+	@NotNull
+	public List<ReportedError> getReportedErrors() {
+		return Collections.unmodifiableList(_reportedErrors);
+	}
+
 	public boolean inContext(String context) {
 		// TODO: useful in parser?
 		return false;
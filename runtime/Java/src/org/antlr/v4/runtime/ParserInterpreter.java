@@ -0,0 +1,333 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime;
+
+import org.antlr.v4.runtime.atn.ATN;
+import org.antlr.v4.runtime.atn.ATNSimulator;
+import org.antlr.v4.runtime.atn.ATNState;
+import org.antlr.v4.runtime.atn.ActionTransition;
+import org.antlr.v4.runtime.atn.AtomTransition;
+import org.antlr.v4.runtime.atn.DecisionState;
+import org.antlr.v4.runtime.atn.LoopEndState;
+import org.antlr.v4.runtime.atn.ParserATNSimulator;
+import org.antlr.v4.runtime.atn.PrecedencePredicateTransition;
+import org.antlr.v4.runtime.atn.PredicateTransition;
+import org.antlr.v4.runtime.atn.PredictionContextCache;
+import org.antlr.v4.runtime.atn.RuleStartState;
+import org.antlr.v4.runtime.atn.RuleTransition;
+import org.antlr.v4.runtime.atn.StarLoopEntryState;
+import org.antlr.v4.runtime.atn.Transition;
+import org.antlr.v4.runtime.dfa.DFA;
+import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Nullable;
+import org.antlr.v4.runtime.misc.Pair;
+
+import java.util.ArrayDeque;
+import java.util.BitSet;
+import java.util.Deque;
+
+/** A parser driven directly by a grammar's ATN, for tools that have a
+ *  grammar's serialized ATN and names but no generated parser class.
+ *  It walks the ATN the way generated rule methods would, keeping its own
+ *  rule invocation stack and building a tree of
+ *  {@link InterpreterRuleContext} nodes. Embedded actions are not
+ *  executed and predicates are evaluated through {@link #sempred}, which
+ *  returns true unless overridden.
+ *
+ *  To give predicates meaning, subclass and override {@link #sempred},
+ *  dispatching on the rule and predicate index the way a generated
+ *  parser's sempred does. The override is used both while predicting
+ *  alternatives and when the parse crosses a predicate.
+ *
+ *  Left-recursive rules are supported; the ATN must come from a tool that
+ *  records the precedence argument of rule references.
+ */
+public class ParserInterpreter extends Parser {
+	protected final String grammarFileName;
+	/** The ATN as given to the constructor, if it was given serialized. */
+	protected final String serializedATN;
+	protected final ATN atn;
+
+	/** States where entering the operator loop of a left-recursive rule
+	 *  pushes a new recursion context, like
+	 *  {@link Parser#pushNewRecursionContext} in generated code.
+	 */
+	protected final BitSet pushRecursionContextStates;
+
+	protected final String[] tokenNames;
+	protected final String[] ruleNames;
+
+	protected final DFA[] _decisionToDFA;
+	protected final PredictionContextCache _sharedContextCache =
+		new PredictionContextCache();
+
+	/** The context and state active when each left-recursive rule
+	 *  invocation began; the generated code keeps these in locals.
+	 */
+	protected final Deque<Pair<ParserRuleContext, Integer>> _parentContextStack =
+		new ArrayDeque<Pair<ParserRuleContext, Integer>>();
+
+	public ParserInterpreter(String grammarFileName,
+							 @NotNull String serializedATN,
+							 String[] tokenNames, String[] ruleNames,
+							 TokenStream input)
+	{
+		this(grammarFileName, serializedATN,
+			 ATNSimulator.deserialize(serializedATN.toCharArray()),
+			 tokenNames, ruleNames, input);
+	}
+
+	public ParserInterpreter(String grammarFileName, @NotNull ATN atn,
+							 String[] tokenNames, String[] ruleNames,
+							 TokenStream input)
+	{
+		this(grammarFileName, null, atn, tokenNames, ruleNames, input);
+	}
+
+	protected ParserInterpreter(String grammarFileName,
+								@Nullable String serializedATN, @NotNull ATN atn,
+								String[] tokenNames, String[] ruleNames,
+								TokenStream input)
+	{
+		super(input);
+		if ( atn.grammarType!=ATN.PARSER ) {
+			throw new IllegalArgumentException("The ATN must be a parser ATN.");
+		}
+		this.grammarFileName = grammarFileName;
+		this.serializedATN = serializedATN;
+		this.atn = atn;
+		this.tokenNames = tokenNames;
+		this.ruleNames = ruleNames;
+
+		this.pushRecursionContextStates = new BitSet(atn.states.size());
+		for (ATNState state : atn.states) {
+			if ( state instanceof StarLoopEntryState &&
+				 ((StarLoopEntryState)state).precedenceRuleDecision )
+			{
+				pushRecursionContextStates.set(state.stateNumber);
+			}
+		}
+
+		this._decisionToDFA = new DFA[atn.getNumberOfDecisions()];
+		setInterpreter(new ParserATNSimulator(this, atn, _decisionToDFA, _sharedContextCache));
+	}
+
+	@Override
+	public ATN getATN() {
+		return atn;
+	}
+
+	/** The serialized ATN, if this interpreter was built from one;
+	 *  otherwise there is nothing to return and this throws like
+	 *  {@link Recognizer#getSerializedATN}.
+	 */
+	@Override
+	public String getSerializedATN() {
+		if ( serializedATN!=null ) return serializedATN;
+		return super.getSerializedATN();
+	}
+
+	@Override
+	public String getGrammarFileName() {
+		return grammarFileName;
+	}
+
+	@Override
+	public String[] getTokenNames() {
+		return tokenNames;
+	}
+
+	@Override
+	public String[] getRuleNames() {
+		return ruleNames;
+	}
+
+	/** Parse starting at rule {@code startRuleIndex} and return the root
+	 *  of the resulting tree.
+	 */
+	public ParserRuleContext parse(int startRuleIndex) {
+		RuleStartState startRuleStartState = atn.ruleToStartState[startRuleIndex];
+		InterpreterRuleContext rootContext =
+			new InterpreterRuleContext(null, ATNState.INVALID_STATE_NUMBER, startRuleIndex);
+		if ( startRuleStartState.isPrecedenceRule ) {
+			enterRecursionRule(rootContext, startRuleIndex, 0);
+		}
+		else {
+			enterRule(rootContext, startRuleStartState.stateNumber, startRuleIndex);
+		}
+		setState(startRuleStartState.stateNumber);
+
+		while ( true ) {
+			ATNState p = atn.states.get(getState());
+			if ( p.getStateType()==ATNState.RULE_STOP ) {
+				if ( _ctx.isEmpty() ) { // returning from the start rule
+					if ( startRuleStartState.isPrecedenceRule ) {
+						ParserRuleContext result = _ctx;
+						Pair<ParserRuleContext, Integer> parent = _parentContextStack.pop();
+						unrollRecursionContexts(parent.a);
+						return result;
+					}
+					exitRule();
+					return rootContext;
+				}
+				visitRuleStopState(p);
+				continue;
+			}
+
+			try {
+				visitState(p);
+			}
+			catch (RecognitionException e) {
+				setState(atn.ruleToStopState[p.ruleIndex].stateNumber);
+				_ctx.exception = e;
+				_errHandler.reportError(this, e);
+				_errHandler.recover(this, e);
+			}
+		}
+	}
+
+	@Override
+	public void enterRecursionRule(ParserRuleContext localctx, int ruleIndex, int precedence) {
+		_parentContextStack.push(new Pair<ParserRuleContext, Integer>(_ctx, localctx.invokingState));
+		super.enterRecursionRule(localctx, ruleIndex, precedence);
+	}
+
+	/** Follow one transition out of {@code p}, predicting which one at
+	 *  decision states, and move to its target.
+	 */
+	protected void visitState(@NotNull ATNState p) {
+		int edge = 1;
+		if ( p.getNumberOfTransitions()>1 ) {
+			edge = getInterpreter().adaptivePredict(_input, ((DecisionState)p).decision, _ctx);
+		}
+
+		Transition transition = p.transition(edge - 1);
+		switch ( transition.getSerializationType() ) {
+			case Transition.EPSILON :
+				if ( pushRecursionContextStates.get(p.stateNumber) &&
+					 !(transition.target instanceof LoopEndState) )
+				{
+					// entering another iteration of the operator loop
+					Pair<ParserRuleContext, Integer> parent = _parentContextStack.peek();
+					InterpreterRuleContext ctx =
+						new InterpreterRuleContext(parent.a, parent.b, _ctx.getRuleIndex());
+					pushNewRecursionContext(ctx, atn.ruleToStartState[p.ruleIndex].stateNumber,
+											_ctx.getRuleIndex());
+				}
+				break;
+
+			case Transition.ATOM :
+				match(((AtomTransition)transition).label);
+				break;
+
+			case Transition.RANGE :
+			case Transition.SET :
+			case Transition.NOT_SET :
+				matchSet(transition);
+				break;
+
+			case Transition.WILDCARD :
+				matchWildcard();
+				break;
+
+			case Transition.RULE :
+				RuleTransition ruleTransition = (RuleTransition)transition;
+				RuleStartState ruleStartState = (RuleStartState)ruleTransition.target;
+				int ruleIndex = ruleStartState.ruleIndex;
+				InterpreterRuleContext ctx = new InterpreterRuleContext(_ctx, p.stateNumber, ruleIndex);
+				if ( ruleStartState.isPrecedenceRule ) {
+					enterRecursionRule(ctx, ruleIndex, ruleTransition.precedence);
+				}
+				else {
+					enterRule(ctx, ruleStartState.stateNumber, ruleIndex);
+				}
+				break;
+
+			case Transition.PREDICATE :
+				PredicateTransition predicateTransition = (PredicateTransition)transition;
+				if ( !sempred(_ctx, predicateTransition.ruleIndex, predicateTransition.predIndex) ) {
+					throw new FailedPredicateException(this);
+				}
+				break;
+
+			case Transition.ACTION :
+				ActionTransition actionTransition = (ActionTransition)transition;
+				action(_ctx, actionTransition.ruleIndex, actionTransition.actionIndex);
+				break;
+
+			case Transition.PRECEDENCE :
+				int precedence = ((PrecedencePredicateTransition)transition).precedence;
+				if ( !precpred(_ctx, precedence) ) {
+					throw new FailedPredicateException(this, "precpred(_ctx, "+precedence+")");
+				}
+				break;
+
+			default :
+				throw new UnsupportedOperationException("Unrecognized ATN transition type.");
+		}
+
+		setState(transition.target.stateNumber);
+	}
+
+	/** Return from the rule that {@code p} ends and continue at the state
+	 *  following the rule reference.
+	 */
+	protected void visitRuleStopState(@NotNull ATNState p) {
+		RuleStartState ruleStartState = atn.ruleToStartState[p.ruleIndex];
+		if ( ruleStartState.isPrecedenceRule ) {
+			Pair<ParserRuleContext, Integer> parent = _parentContextStack.pop();
+			unrollRecursionContexts(parent.a);
+			setState(parent.b);
+		}
+		else {
+			exitRule();
+		}
+
+		RuleTransition ruleTransition = (RuleTransition)atn.states.get(getState()).transition(0);
+		setState(ruleTransition.followState.stateNumber);
+	}
+
+	/** Like {@link #match} for a set, range or not-set transition. */
+	protected Token matchSet(@NotNull Transition transition) {
+		Token t = getCurrentToken();
+		if ( transition.matches(t.getType(), Token.MIN_USER_TOKEN_TYPE, atn.maxTokenType) ) {
+			_errHandler.endErrorCondition(this);
+			consume();
+		}
+		else {
+			t = _errHandler.recoverInline(this);
+			if ( _buildParseTrees && t.getTokenIndex()==-1 ) {
+				// conjured up by single token insertion
+				addErrorNode(t);
+			}
+		}
+		return t;
+	}
+}
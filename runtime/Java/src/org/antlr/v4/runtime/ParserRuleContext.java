@@ -280,6 +280,16 @@ public class ParserRuleContext extends RuleContext {
 		return Interval.of(start.getTokenIndex(), stop.getTokenIndex());
 	}
 
+	/** Return every token from start to stop inclusive as buffered by
+	 *  {@code tokens}, including tokens on hidden channels such as comments
+	 *  and whitespace. Empty if this rule matched no tokens.
+	 */
+	public List<Token> getFullTokenRange(BufferedTokenStream tokens) {
+		Interval range = getSourceInterval();
+		if ( range.a<0 || range.b<range.a ) return Collections.emptyList();
+		return tokens.get(range.a, range.b);
+	}
+
 	public Token getStart() { return start; }
 	public Token getStop() { return stop; }
 
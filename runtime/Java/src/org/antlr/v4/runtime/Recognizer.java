@@ -35,6 +35,8 @@ import org.antlr.v4.runtime.atn.ATNSimulator;
 import org.antlr.v4.runtime.misc.NotNull;
 import org.antlr.v4.runtime.misc.Nullable;
 
+import java.security.MessageDigest;
+import java.security.NoSuchAlgorithmException;
 import java.util.List;
 import java.util.concurrent.CopyOnWriteArrayList;
 
@@ -66,6 +68,56 @@ public abstract class Recognizer<Symbol, ATNInterpreter extends ATNSimulator> {
 
 	public abstract ATN getATN();
 
+	/** The serialized ATN this recognizer was generated with. Generated
+	 *  recognizers override this; it is used by {@link #getGrammarFingerprint}.
+	 */
+	public String getSerializedATN() {
+		throw new UnsupportedOperationException("there is no serialized ATN available");
+	}
+
+	/** Return a stable hash (hex SHA-1) of the serialized ATN, token names
+	 *  and rule names. It changes whenever the grammar changes in a way
+	 *  that affects recognition or the vocabulary, so caches of DFAs,
+	 *  token streams or parse results can be keyed by it. It is computed
+	 *  on every call.
+	 */
+	public String getGrammarFingerprint() {
+		MessageDigest digest;
+		try {
+			digest = MessageDigest.getInstance("SHA-1");
+		}
+		catch (NoSuchAlgorithmException nsae) {
+			throw new IllegalStateException(nsae); // every JVM must provide SHA-1
+		}
+		update(digest, getSerializedATN());
+		update(digest, getTokenNames());
+		update(digest, getRuleNames());
+		StringBuilder buf = new StringBuilder();
+		for (byte b : digest.digest()) {
+			buf.append(Character.forDigit((b>>4) & 0xF, 16));
+			buf.append(Character.forDigit(b & 0xF, 16));
+		}
+		return buf.toString();
+	}
+
+	private static void update(MessageDigest digest, String[] names) {
+		if ( names==null ) return;
+		for (String name : names) {
+			if ( name!=null ) update(digest, name);
+		}
+	}
+
+	private static void update(MessageDigest digest, String s) {
+		// hash the raw chars; the serialized ATN isn't valid UTF-16 text
+		for (int i=0; i<s.length(); i++) {
+			char c = s.charAt(i);
+			digest.update((byte)(c>>8));
+			digest.update((byte)c);
+		}
+		digest.update((byte)0); // separator
+		digest.update((byte)0);
+	}
+
 	public ATNInterpreter getInterpreter() {
 		return _interp;
 	}
@@ -140,6 +192,10 @@ public abstract class Recognizer<Symbol, ATNInterpreter extends ATNSimulator> {
 		return true;
 	}
 
+	public boolean precpred(@Nullable RuleContext localctx, int precedence) {
+		return true;
+	}
+
 	public void action(@Nullable RuleContext _localctx, int ruleIndex, int actionIndex) {
 	}
 
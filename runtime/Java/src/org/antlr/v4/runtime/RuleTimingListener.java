@@ -0,0 +1,131 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime;
+
+import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.tree.ErrorNode;
+import org.antlr.v4.runtime.tree.ParseTreeListener;
+import org.antlr.v4.runtime.tree.TerminalNode;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Comparator;
+import java.util.List;
+
+/** A parse listener that attributes wall time to rule invocations. Add
+ *  it with {@link Parser#addParseListener} before parsing; afterwards,
+ *  {@link #toString} gives a per-rule report sorted by self time. If it
+ *  is added during the parse, rules already active are not timed.
+ *
+ *  Self time excludes time spent in invoked rules. Cumulative time
+ *  includes it but counts a recursive rule only once per outermost
+ *  invocation. Times are in nanoseconds and include the overhead of
+ *  the listener itself.
+ */
+public class RuleTimingListener implements ParseTreeListener {
+	@NotNull
+	protected final String[] ruleNames;
+
+	protected final int[] invocations;
+	protected final long[] selfTime;
+	protected final long[] cumulativeTime;
+
+	/** How many invocations of each rule are on the stack */
+	protected final int[] active;
+
+	/** One {ruleIndex, start time, time in invoked rules} per active invocation */
+	protected final List<long[]> frames = new ArrayList<long[]>();
+
+	public RuleTimingListener(@NotNull Parser parser) {
+		ruleNames = parser.getRuleNames();
+		invocations = new int[ruleNames.length];
+		selfTime = new long[ruleNames.length];
+		cumulativeTime = new long[ruleNames.length];
+		active = new int[ruleNames.length];
+	}
+
+	@Override
+	public void enterEveryRule(ParserRuleContext ctx) {
+		int r = ctx.getRuleIndex();
+		invocations[r]++;
+		active[r]++;
+		frames.add(new long[] {r, System.nanoTime(), 0});
+	}
+
+	@Override
+	public void exitEveryRule(ParserRuleContext ctx) {
+		long now = System.nanoTime();
+		// added mid-parse, e.g. from a rule's @init: we missed the enter
+		if ( frames.isEmpty() ) return;
+		long[] frame = frames.remove(frames.size()-1);
+		int r = (int)frame[0];
+		long elapsed = now - frame[1];
+		selfTime[r] += elapsed - frame[2];
+		active[r]--;
+		if ( active[r]==0 ) cumulativeTime[r] += elapsed;
+		if ( !frames.isEmpty() ) frames.get(frames.size()-1)[2] += elapsed;
+	}
+
+	@Override
+	public void visitTerminal(TerminalNode node) { }
+
+	@Override
+	public void visitErrorNode(ErrorNode node) { }
+
+	public int getInvocations(int ruleIndex) { return invocations[ruleIndex]; }
+
+	public long getSelfTime(int ruleIndex) { return selfTime[ruleIndex]; }
+
+	public long getCumulativeTime(int ruleIndex) { return cumulativeTime[ruleIndex]; }
+
+	/** One line per invoked rule: name, invocations, self and cumulative
+	 *  milliseconds; slowest self time first.
+	 */
+	@Override
+	public String toString() {
+		Integer[] rules = new Integer[ruleNames.length];
+		for (int r=0; r<rules.length; r++) rules[r] = r;
+		Arrays.sort(rules, new Comparator<Integer>() {
+			@Override
+			public int compare(Integer a, Integer b) {
+				if ( selfTime[a]!=selfTime[b] ) return selfTime[a] > selfTime[b] ? -1 : 1;
+				return a - b;
+			}
+		});
+		StringBuilder buf = new StringBuilder();
+		buf.append(String.format("%-20s %10s %12s %12s%n", "rule", "calls", "self ms", "cumul ms"));
+		for (int r : rules) {
+			if ( invocations[r]==0 ) continue;
+			buf.append(String.format("%-20s %10d %12.3f %12.3f%n", ruleNames[r], invocations[r],
+									 selfTime[r] / 1e6, cumulativeTime[r] / 1e6));
+		}
+		return buf.toString();
+	}
+}
@@ -57,6 +57,14 @@ public interface Token {
 	 */
 	public static final int HIDDEN_CHANNEL = 1;
 
+	/** This is the minimum constant value which can be assigned to a
+	 *  user-defined token channel. Channels below this value are reserved
+	 *  for {@link #DEFAULT_CHANNEL} and {@link #HIDDEN_CHANNEL}.
+	 *
+	 *  @see Lexer#getChannelNames
+	 */
+	public static final int MIN_USER_CHANNEL_VALUE = 2;
+
 	/** Get the text of the token */
 	String getText();
 
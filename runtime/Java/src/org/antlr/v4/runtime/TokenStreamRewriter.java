@@ -508,7 +508,6 @@ public class TokenStreamRewriter {
 					rewrites.set(prevRop.instructionIndex, null); // kill first delete
 					rop.index = Math.min(prevRop.index, rop.index);
 					rop.lastIndex = Math.max(prevRop.lastIndex, rop.lastIndex);
-					System.out.println("new rop "+rop);
 				}
 				else if ( !disjoint && !same ) {
 					throw new IllegalArgumentException("replace op boundaries of "+rop+
@@ -169,7 +169,7 @@ public class UnbufferedCharStream implements CharStream {
 	 */
 	protected int fill(int n) {
 		for (int i=0; i<n; i++) {
-			if (this.n > 0 && data[this.n - 1] == IntStream.EOF) {
+			if (this.n > 0 && data[this.n - 1] == (char)IntStream.EOF) {
 				return i;
 			}
 
@@ -308,8 +308,13 @@ public class UnbufferedCharStream implements CharStream {
 
 		int bufferStartIndex = getBufferStartIndex();
 		if (n > 0 && data[n - 1] == Character.MAX_VALUE) {
-			if (interval.a + interval.length() > bufferStartIndex + n) {
-				throw new IllegalArgumentException("the interval extends past the end of the stream");
+			// EOF is buffered; clamp to the last char like ANTLRInputStream
+			int last = bufferStartIndex + n - 2;
+			if (interval.a > last) {
+				return "";
+			}
+			if (interval.b > last) {
+				interval = Interval.of(interval.a, last);
 			}
 		}
 
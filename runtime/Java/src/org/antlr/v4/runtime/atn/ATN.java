@@ -31,11 +31,14 @@
 package org.antlr.v4.runtime.atn;
 
 import org.antlr.v4.runtime.RuleContext;
+import org.antlr.v4.runtime.Token;
+import org.antlr.v4.runtime.misc.IntegerStack;
 import org.antlr.v4.runtime.misc.IntervalSet;
 import org.antlr.v4.runtime.misc.NotNull;
 import org.antlr.v4.runtime.misc.Nullable;
 
 import java.util.ArrayList;
+import java.util.BitSet;
 import java.util.LinkedHashMap;
 import java.util.List;
 import java.util.Map;
@@ -68,6 +71,11 @@ public class ATN {
 	public int grammarType; // ATN.LEXER, ...
 	public int maxTokenType;
 
+	/** Set by {@link #pruneUnreachableRules}; until then no rule is
+	 *  missing and parsers needn't check for pruned rules.
+	 */
+	public boolean hasPrunedRules;
+
 	// runtime for lexer only
 	public int[] ruleToTokenType;
 	public int[] ruleToActionIndex;
@@ -102,6 +110,168 @@ public class ATN {
         return s.nextTokenWithinRule;
     }
 
+	/** Compute FIRST(r), the set of tokens that can begin rule r.
+	 *  EPSILON is in set if r can match the empty input.
+	 */
+	@NotNull
+	public IntervalSet firstOfRule(int ruleIndex) {
+		return nextTokens(ruleToStartState[ruleIndex]);
+	}
+
+	/** Compute the set of tokens that can follow rule r. If ctx is
+	 *  non-null, it must be a context for an invocation of r; the set
+	 *  is then what can follow that particular invocation, per
+	 *  {@link #nextTokens(ATNState, RuleContext)}. If ctx is null, the
+	 *  set is the classic FOLLOW(r): the union over every rule reference
+	 *  to r of what can follow the reference. EPSILON is in set if r
+	 *  can end a rule that no other rule invokes (the start rule).
+	 */
+	@NotNull
+	public IntervalSet followOfRule(int ruleIndex, @Nullable RuleContext ctx) {
+		if ( ctx!=null ) return nextTokens(ruleToStopState[ruleIndex], ctx);
+		IntervalSet follow = new IntervalSet();
+		followOfRule(ruleIndex, new BitSet(), follow);
+		return follow;
+	}
+
+	protected void followOfRule(int ruleIndex, @NotNull BitSet busy, @NotNull IntervalSet follow) {
+		if ( busy.get(ruleIndex) ) return;
+		busy.set(ruleIndex);
+		boolean invoked = false;
+		for (ATNState s : states) {
+			if ( s==null ) continue;
+			for (int i=0; i<s.getNumberOfTransitions(); i++) {
+				Transition t = s.transition(i);
+				if ( !(t instanceof RuleTransition) || t.target.ruleIndex!=ruleIndex ) continue;
+				invoked = true;
+				IntervalSet next = nextTokens(((RuleTransition)t).followState);
+				boolean canEnd = next.contains(Token.EPSILON);
+				next = new IntervalSet(next); // cached sets are readonly
+				next.remove(Token.EPSILON);
+				follow.addAll(next);
+				if ( canEnd ) {
+					// reference can end the invoking rule; add what follows that
+					followOfRule(s.ruleIndex, busy, follow);
+				}
+			}
+		}
+		if ( !invoked ) follow.add(Token.EPSILON);
+	}
+
+	/** Compute the rule call graph: element r is the set of rules
+	 *  that rule r references, derived from the rule transitions.
+	 */
+	@NotNull
+	public BitSet[] getRuleDependencies() {
+		BitSet[] deps = new BitSet[ruleToStartState.length];
+		for (int r=0; r<deps.length; r++) deps[r] = new BitSet();
+		for (ATNState s : states) {
+			if ( s==null ) continue;
+			for (int i=0; i<s.getNumberOfTransitions(); i++) {
+				Transition t = s.transition(i);
+				if ( t instanceof RuleTransition ) {
+					deps[s.ruleIndex].set(t.target.ruleIndex);
+				}
+			}
+		}
+		return deps;
+	}
+
+	/** Emit the rule call graph from {@link #getRuleDependencies} in DOT
+	 *  format, one node per rule and one edge per referenced rule.
+	 */
+	public String getRuleDependencyDOT(@Nullable String[] ruleNames) {
+		BitSet[] deps = getRuleDependencies();
+		StringBuilder buf = new StringBuilder();
+		buf.append("digraph G {\n");
+		buf.append("rankdir=LR;\n");
+		for (int r=0; r<deps.length; r++) {
+			buf.append("  r").append(r);
+			buf.append(" [label=\"").append(ruleName(ruleNames, r)).append("\"];\n");
+		}
+		for (int r=0; r<deps.length; r++) {
+			for (int d=deps[r].nextSetBit(0); d>=0; d=deps[r].nextSetBit(d+1)) {
+				buf.append("  r").append(r).append("->r").append(d).append(";\n");
+			}
+		}
+		buf.append("}\n");
+		return buf.toString();
+	}
+
+	/** Compute the rules reachable from {@code startRules} through rule
+	 *  references, including the start rules themselves.
+	 */
+	@NotNull
+	public BitSet getReachableRules(int... startRules) {
+		BitSet[] deps = getRuleDependencies();
+		BitSet reachable = new BitSet();
+		IntegerStack work = new IntegerStack();
+		for (int r : startRules) work.push(r);
+		while ( !work.isEmpty() ) {
+			int r = work.pop();
+			if ( reachable.get(r) ) continue;
+			reachable.set(r);
+			for (int d=deps[r].nextSetBit(0); d>=0; d=deps[r].nextSetBit(d+1)) {
+				work.push(d);
+			}
+		}
+		return reachable;
+	}
+
+	/** Remove the states of every rule not reachable from
+	 *  {@code startRules}, for applications that only ever parse a subset
+	 *  of a parser grammar, along with every transition into them. State
+	 *  and decision numbers of the remaining rules don't change. Removed
+	 *  rules get null start and stop states, and Parser.enterRule refuses
+	 *  to enter them rather than fail somewhere inside prediction.
+	 *
+	 *  Generated parsers share one static ATN and one DFA cache per
+	 *  grammar, and this neither copies the ATN nor clears the DFA of the
+	 *  removed decisions. Use Parser.pruneUnreachableRules to prune a copy
+	 *  that only one parser uses; prune a shared ATN only before any
+	 *  parser has used it.
+	 *
+	 *  @return the number of states removed
+	 */
+	public int pruneUnreachableRules(int... startRules) {
+		if ( grammarType==LEXER ) {
+			throw new UnsupportedOperationException("can't prune lexer ATNs");
+		}
+		BitSet reachable = getReachableRules(startRules);
+		hasPrunedRules = true;
+		int removed = 0;
+		for (ATNState s : states) {
+			if ( s!=null && !reachable.get(s.ruleIndex) ) {
+				removeState(s);
+				removed++;
+			}
+		}
+		// drop edges into removed rules, mainly the follow links from the
+		// stop states of kept rules back into removed callers, so
+		// prediction can't fall off a rule into them
+		for (ATNState s : states) {
+			if ( s==null ) continue;
+			for (int i=s.getNumberOfTransitions()-1; i>=0; i--) {
+				if ( !reachable.get(s.transition(i).target.ruleIndex) ) s.removeTransition(i);
+			}
+		}
+		for (int r=0; r<ruleToStartState.length; r++) {
+			if ( reachable.get(r) ) continue;
+			ruleToStartState[r] = null;
+			ruleToStopState[r] = null;
+		}
+		for (int d=0; d<decisionToState.size(); d++) {
+			DecisionState s = decisionToState.get(d);
+			if ( s!=null && !reachable.get(s.ruleIndex) ) decisionToState.set(d, null);
+		}
+		return removed;
+	}
+
+	protected String ruleName(@Nullable String[] ruleNames, int ruleIndex) {
+		if ( ruleNames!=null && ruleIndex<ruleNames.length ) return ruleNames[ruleIndex];
+		return String.valueOf(ruleIndex);
+	}
+
 	public void addState(@Nullable ATNState state) {
 		if ( state==null ) { states.add(null); stateNumber++; return; }
 		state.atn = this;
@@ -43,7 +43,7 @@ import java.util.List;
 public abstract class ATNSimulator {
 	public static final int SERIALIZED_VERSION;
 	static {
-		SERIALIZED_VERSION = 2;
+		SERIALIZED_VERSION = 3;
 	}
 
 	/** Must distinguish between missing edge and edge we know leads nowhere */
@@ -159,6 +159,12 @@ public abstract class ATNSimulator {
 			((DecisionState)atn.states.get(stateNumber)).nonGreedy = true;
 		}
 
+		int numPrecedenceStates = toInt(data[p++]);
+		for (int i = 0; i < numPrecedenceStates; i++) {
+			int stateNumber = toInt(data[p++]);
+			((RuleStartState)atn.states.get(stateNumber)).isPrecedenceRule = true;
+		}
+
 		//
 		// RULES
 		//
@@ -295,10 +301,32 @@ public abstract class ATNSimulator {
 			decState.decision = i-1;
 		}
 
+		markPrecedenceDecisions(atn);
+
 		verifyATN(atn);
 		return atn;
 	}
 
+	/** Find the operator loop of each left-recursive rule, the
+	 *  {@link StarLoopEntryState} whose exit branch leads straight to the
+	 *  rule's stop state, and set its
+	 *  {@link StarLoopEntryState#precedenceRuleDecision} flag.
+	 */
+	protected static void markPrecedenceDecisions(@NotNull ATN atn) {
+		for (ATNState state : atn.states) {
+			if ( !(state instanceof StarLoopEntryState) ) continue;
+			if ( !atn.ruleToStartState[state.ruleIndex].isPrecedenceRule ) continue;
+
+			ATNState maybeLoopEndState = state.transition(state.getNumberOfTransitions() - 1).target;
+			if ( maybeLoopEndState instanceof LoopEndState &&
+				 maybeLoopEndState.onlyHasEpsilonTransitions() &&
+				 maybeLoopEndState.transition(0).target instanceof RuleStopState )
+			{
+				((StarLoopEntryState)state).precedenceRuleDecision = true;
+			}
+		}
+	}
+
 	private static void verifyATN(ATN atn) {
 		// verify assumptions
 		for (ATNState state : atn.states) {
@@ -386,11 +414,13 @@ public abstract class ATNSimulator {
 			case Transition.EPSILON : return new EpsilonTransition(target);
 			case Transition.RANGE : return new RangeTransition(target, arg1, arg2);
 			case Transition.RULE :
-				RuleTransition rt = new RuleTransition((RuleStartState)atn.states.get(arg1), arg2, target);
+				RuleTransition rt = new RuleTransition((RuleStartState)atn.states.get(arg1), arg2, arg3, target);
 				return rt;
 			case Transition.PREDICATE :
 				PredicateTransition pt = new PredicateTransition(target, arg1, arg2, arg3 != 0);
 				return pt;
+			case Transition.PRECEDENCE :
+				return new PrecedencePredicateTransition(target, arg1);
 			case Transition.ATOM : return new AtomTransition(target, arg1);
 			case Transition.ACTION :
 				ActionTransition a = new ActionTransition(target, arg1, arg2, arg3 != 0);
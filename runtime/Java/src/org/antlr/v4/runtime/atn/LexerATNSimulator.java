@@ -34,6 +34,7 @@ import org.antlr.v4.runtime.CharStream;
 import org.antlr.v4.runtime.IntStream;
 import org.antlr.v4.runtime.Lexer;
 import org.antlr.v4.runtime.LexerNoViableAltException;
+import org.antlr.v4.runtime.LexerScanLimitException;
 import org.antlr.v4.runtime.Token;
 import org.antlr.v4.runtime.dfa.DFA;
 import org.antlr.v4.runtime.dfa.DFAState;
@@ -90,6 +91,16 @@ public class LexerATNSimulator extends ATNSimulator {
 	 */
 	protected int startIndex = -1;
 
+	/** If positive, the most chars a single token may span; see
+	 *  {@link #setMaxTokenLength}.
+	 */
+	protected int maxTokenLength = 0;
+
+	/** If positive, the most chars scanned past the last accepted token;
+	 *  see {@link #setMaxScanAhead}.
+	 */
+	protected int maxScanAhead = 0;
+
 	/** line number 1..n within the input */
 	protected int line = 1;
 
@@ -157,6 +168,55 @@ public class LexerATNSimulator extends ATNSimulator {
 		}
 	}
 
+	/** Return the lexer rule that matched the token found by the last
+	 *  {@link #match}, or -1 if it found no token.
+	 */
+	public int getMatchedRuleIndex() {
+		return prevAccept.dfaState!=null ? prevAccept.dfaState.lexerRuleIndex : -1;
+	}
+
+	/** Return the action executed for the token found by the last
+	 *  {@link #match}, or -1 if there was none.
+	 */
+	public int getMatchedActionIndex() {
+		return prevAccept.dfaState!=null ? prevAccept.dfaState.lexerActionIndex : -1;
+	}
+
+	/** Limit the length of a single token. Once {@code maxTokenLength}
+	 *  chars have been scanned for one token and the lexer could still go
+	 *  on, the token is reported as a token recognition error covering
+	 *  those chars, even if a shorter token matched along the way, and
+	 *  lexing resumes right after them. This bounds the work (and, for
+	 *  unbuffered streams, the buffering) spent on input like an
+	 *  unterminated string in a huge file. Zero or negative means no
+	 *  limit, the default.
+	 */
+	public void setMaxTokenLength(int maxTokenLength) {
+		this.maxTokenLength = maxTokenLength;
+	}
+
+	public int getMaxTokenLength() {
+		return maxTokenLength;
+	}
+
+	/** Limit how far the lexer speculates past the last token it matched
+	 *  while looking for a longer one. After {@code maxScanAhead} chars
+	 *  without reaching another accept state, the lexer gives up on the
+	 *  longer match and returns the token it already has, just as if the
+	 *  longer match had failed; if nothing matched yet, the chars scanned
+	 *  are reported as a token recognition error. Unlike
+	 *  {@link #setMaxTokenLength}, this never rejects a long token that
+	 *  keeps matching, only long stretches of unproductive lookahead.
+	 *  Zero or negative means no limit, the default.
+	 */
+	public void setMaxScanAhead(int maxScanAhead) {
+		this.maxScanAhead = maxScanAhead;
+	}
+
+	public int getMaxScanAhead() {
+		return maxScanAhead;
+	}
+
 	@Override
 	public void reset() {
 		prevAccept.reset();
@@ -271,6 +331,21 @@ public class LexerATNSimulator extends ATNSimulator {
 			}
 
 			if (t != IntStream.EOF) {
+				if ( maxTokenLength>0 && input.index()-startIndex >= maxTokenLength ) {
+					// consuming t would make the token too long
+					throw new LexerScanLimitException(recog, input, startIndex, target.configs);
+				}
+				if ( maxScanAhead>0 ) {
+					int lastAcceptStop = prevAccept.dfaState!=null ? prevAccept.index+1 : startIndex;
+					if ( input.index()-lastAcceptStop >= maxScanAhead ) {
+						// speculated far enough; settle for what we have
+						if ( prevAccept.dfaState==null ) {
+							throw new LexerScanLimitException(recog, input, startIndex, target.configs);
+						}
+						s = target;
+						break;
+					}
+				}
 				consume(input);
 				t = input.LA(1);
 			}
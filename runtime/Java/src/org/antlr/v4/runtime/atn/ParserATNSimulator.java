@@ -50,8 +50,10 @@ import java.util.ArrayList;
 import java.util.Arrays;
 import java.util.BitSet;
 import java.util.Collection;
+import java.util.HashMap;
 import java.util.HashSet;
 import java.util.List;
+import java.util.Map;
 import java.util.Set;
 
 /**
@@ -340,23 +342,30 @@ public class ParserATNSimulator extends ATNSimulator {
 		}
 		// Now we are certain to have a specific decision's DFA
 		// But, do we still need an initial state?
-		if ( dfa.s0==null ) { // recheck
-			if ( dfa.s0==null ) { // recheck
-				try {
-					return predictATN(dfa, input, outerContext);
-				}
-				finally {
-					mergeCache = null; // wack cache after each prediction
-				}
+		DFAState s0;
+		if ( dfa.isPrecedenceDfa() ) {
+			// the start state for a precedence DFA depends on the current
+			// parser precedence, and is provided by a DFA method.
+			s0 = dfa.getPrecedenceStartState(parser.getPrecedence());
+		}
+		else {
+			s0 = dfa.s0;
+		}
+
+		if ( s0==null ) {
+			try {
+				return predictATN(dfa, input, outerContext);
+			}
+			finally {
+				mergeCache = null; // wack cache after each prediction
 			}
-			// fall through; another thread set dfa.s0 while we waited for lock
 		}
 
 		// We can start with an existing DFA
 		int m = input.mark();
 		int index = input.index();
 		try {
-			return execDFA(dfa, dfa.s0, input, index, outerContext);
+			return execDFA(dfa, s0, input, index, outerContext);
 		}
 		finally {
 			mergeCache = null; // wack cache after each prediction
@@ -380,13 +389,27 @@ public class ParserATNSimulator extends ATNSimulator {
 			computeStartState(dfa.atnStartState,
 							  ParserRuleContext.EMPTY,
 							  fullCtx);
-		dfa.s0 = addDFAState(dfa, new DFAState(s0_closure));
+		DFAState s0;
+		if ( dfa.isPrecedenceDfa() ) {
+			// If this is a precedence DFA, we use applyPrecedenceFilter
+			// to convert the computed start state to a precedence start
+			// state. We then use DFA.setPrecedenceStartState to set the
+			// appropriate start state for the precedence level rather
+			// than simply setting DFA.s0.
+			s0_closure = applyPrecedenceFilter(s0_closure, outerContext);
+			s0 = addDFAState(dfa, new DFAState(s0_closure));
+			dfa.setPrecedenceStartState(parser.getPrecedence(), s0);
+		}
+		else {
+			s0 = addDFAState(dfa, new DFAState(s0_closure));
+			dfa.s0 = s0;
+		}
 
 		int alt = 0;
 		int m = input.mark();
 		int index = input.index();
 		try {
-			alt = execATN(dfa, dfa.s0, input, index, outerContext);
+			alt = execATN(dfa, s0, input, index, outerContext);
 		}
 		catch (NoViableAltException nvae) {
 			if ( debug ) dumpDeadEndConfigs(nvae);
@@ -1072,6 +1095,56 @@ public class ParserATNSimulator extends ATNSimulator {
 		return configs;
 	}
 
+	/** Turn the start state computed by {@link #computeStartState} for the
+	 *  operator loop of a left-recursive rule into the start state for the
+	 *  parser's current precedence level.
+	 *  <ol>
+	 *  <li>Evaluate the precedence predicates of the configurations that
+	 *  enter the loop (alt 1). Remove a configuration if its predicates fail;
+	 *  otherwise strip the precedence predicates from it.</li>
+	 *  <li>Remove configurations of other alts that have the same state and
+	 *  context as a remaining alt 1 configuration. Those could never win an
+	 *  ambiguity against alt 1 anyway.</li>
+	 *  </ol>
+	 *  Since the result depends only on the precedence level and not on the
+	 *  input, it can be cached in the DFA.
+	 *
+	 *  @see DFA#setPrecedenceStartState
+	 */
+	@NotNull
+	protected ATNConfigSet applyPrecedenceFilter(@NotNull ATNConfigSet configs,
+												 @NotNull ParserRuleContext outerContext)
+	{
+		Map<Integer, PredictionContext> statesFromAlt1 = new HashMap<Integer, PredictionContext>();
+		ATNConfigSet configSet = new ATNConfigSet(configs.fullCtx);
+		for (ATNConfig config : configs) {
+			// handle alt 1 first
+			if ( config.alt != 1 ) continue;
+
+			SemanticContext updatedContext = config.semanticContext.evalPrecedence(parser, outerContext);
+			if ( updatedContext==null ) continue; // the configuration was eliminated
+
+			statesFromAlt1.put(config.state.stateNumber, config.context);
+			if ( updatedContext != config.semanticContext ) {
+				configSet.add(new ATNConfig(config, updatedContext), mergeCache);
+			}
+			else {
+				configSet.add(config, mergeCache);
+			}
+		}
+
+		for (ATNConfig config : configs) {
+			if ( config.alt == 1 ) continue; // already handled
+
+			PredictionContext context = statesFromAlt1.get(config.state.stateNumber);
+			if ( context!=null && context.equals(config.context) ) continue; // eliminated
+
+			configSet.add(config, mergeCache);
+		}
+
+		return configSet;
+	}
+
 	@Nullable
 	public ATNState getReachableTarget(@NotNull Transition trans, int ttype) {
 		if (trans.matches(ttype, 0, atn.maxTokenType)) {
@@ -1345,6 +1418,12 @@ public class ParserATNSimulator extends ATNSimulator {
 		case Transition.RULE:
 			return ruleTransition(config, (RuleTransition)t);
 
+		case Transition.PRECEDENCE:
+			return precedenceTransition(config, (PrecedencePredicateTransition)t,
+										collectPredicates,
+										inContext,
+										fullCtx);
+
 		case Transition.PREDICATE:
 			return predTransition(config, (PredicateTransition)t,
 								  collectPredicates,
@@ -1368,6 +1447,50 @@ public class ParserATNSimulator extends ATNSimulator {
 		return new ATNConfig(config, t.target);
 	}
 
+	@Nullable
+	public ATNConfig precedenceTransition(@NotNull ATNConfig config,
+										  @NotNull PrecedencePredicateTransition pt,
+										  boolean collectPredicates,
+										  boolean inContext,
+										  boolean fullCtx)
+	{
+		if ( debug ) {
+			System.out.println("PRED (collectPredicates="+collectPredicates+") "+
+                    pt.precedence+">=_p"+
+					", ctx dependent=true");
+			if ( parser != null ) {
+                System.out.println("context surrounding pred is "+
+                                   parser.getRuleInvocationStack());
+            }
+		}
+
+		ATNConfig c = null;
+		// precedence predicates always depend on the invoking rule's context
+		if ( collectPredicates && inContext ) {
+			if ( fullCtx ) {
+				// see predTransition; evaluate on-the-fly in full context mode
+				int currentPosition = _input.index();
+				_input.seek(_startIndex);
+				boolean predSucceeds = pt.getPredicate().eval(parser, _outerContext);
+				_input.seek(currentPosition);
+				if ( predSucceeds ) {
+					c = new ATNConfig(config, pt.target); // no pred context
+				}
+			}
+			else {
+				SemanticContext newSemCtx =
+					SemanticContext.and(config.semanticContext, pt.getPredicate());
+				c = new ATNConfig(config, pt.target, newSemCtx);
+			}
+		}
+		else {
+			c = new ATNConfig(config, pt.target);
+		}
+
+		if ( debug ) System.out.println("config from pred transition="+c);
+        return c;
+	}
+
 	@Nullable
 	public ATNConfig predTransition(@NotNull ATNConfig config,
 									@NotNull PredicateTransition pt,
@@ -0,0 +1,74 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+package org.antlr.v4.runtime.atn;
+
+import org.antlr.v4.runtime.misc.NotNull;
+
+/** The predicate {@code {precedence >= _p}?} that the tool inserts in front
+ *  of each operator alternative of a left-recursive rule. Unlike an ordinary
+ *  {@link PredicateTransition}, its value depends only on the precedence of
+ *  the current rule invocation, which lets the prediction DFA for the
+ *  operator loop cache one start state per precedence level.
+ *
+ *  @see org.antlr.v4.runtime.dfa.DFA#isPrecedenceDfa
+ */
+public final class PrecedencePredicateTransition extends AbstractPredicateTransition {
+	public final int precedence;
+
+	public PrecedencePredicateTransition(@NotNull ATNState target, int precedence) {
+		super(target);
+		this.precedence = precedence;
+	}
+
+	@Override
+	public int getSerializationType() {
+		return PRECEDENCE;
+	}
+
+	@Override
+	public boolean isEpsilon() { return true; }
+
+	@Override
+	public boolean matches(int symbol, int minVocabSymbol, int maxVocabSymbol) {
+		return false;
+	}
+
+	public SemanticContext.PrecedencePredicate getPredicate() {
+		return new SemanticContext.PrecedencePredicate(precedence);
+	}
+
+	@Override
+	@NotNull
+	public String toString() {
+		return precedence + " >= _p";
+	}
+
+}
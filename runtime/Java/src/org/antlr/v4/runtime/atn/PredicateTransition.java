@@ -38,7 +38,7 @@ import org.antlr.v4.runtime.misc.NotNull;
  *  may have to combine a bunch of them as it collects predicates from
  *  multiple ATN configurations into a single DFA state.
  */
-public final class PredicateTransition extends Transition {
+public final class PredicateTransition extends AbstractPredicateTransition {
 	public final int ruleIndex;
 	public final int predIndex;
 	public final boolean isCtxDependent;  // e.g., $i ref in pred
@@ -32,6 +32,10 @@ package org.antlr.v4.runtime.atn;
 
 public final class RuleStartState extends ATNState {
 	public RuleStopState stopState;
+	/** Is this the start state of a left-recursive rule whose operator
+	 *  alternatives are guarded by precedence predicates?
+	 */
+	public boolean isPrecedenceRule;
 
 	@Override
 	public int getStateType() {
@@ -37,6 +37,11 @@ public final class RuleTransition extends Transition {
 	/** Ptr to the rule definition object for this rule ref */
 	public final int ruleIndex;     // no Rule object at runtime
 
+	/** Precedence argument passed to a left-recursive rule, as in
+	 *  {@code e[4]}; 0 for all other rule references.
+	 */
+	public final int precedence;
+
 	/** What node to begin computations following ref to rule */
 	@NotNull
 	public ATNState followState;
@@ -44,9 +49,18 @@ public final class RuleTransition extends Transition {
 	public RuleTransition(@NotNull RuleStartState ruleStart,
 						  int ruleIndex,
 						  @NotNull ATNState followState)
+	{
+		this(ruleStart, ruleIndex, 0, followState);
+	}
+
+	public RuleTransition(@NotNull RuleStartState ruleStart,
+						  int ruleIndex,
+						  int precedence,
+						  @NotNull ATNState followState)
 	{
 		super(ruleStart);
 		this.ruleIndex = ruleIndex;
+		this.precedence = precedence;
 		this.followState = followState;
 	}
 
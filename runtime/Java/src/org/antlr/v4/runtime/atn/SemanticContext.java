@@ -33,6 +33,7 @@ package org.antlr.v4.runtime.atn;
 import org.antlr.v4.runtime.Recognizer;
 import org.antlr.v4.runtime.RuleContext;
 import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Nullable;
 import org.antlr.v4.runtime.misc.Utils;
 
 import java.util.Arrays;
@@ -66,6 +67,19 @@ public abstract class SemanticContext {
 	 */
     public abstract boolean eval(Recognizer<?,?> parser, RuleContext outerContext);
 
+	/**
+	 * Evaluate the precedence predicates in this context, leaving any other
+	 * predicates in place.
+	 *
+	 * @return {@code null} if a precedence predicate is false (so the whole
+	 * context is false), {@link #NONE} if no predicates remain, otherwise the
+	 * context that remains once the precedence predicates are removed
+	 */
+	@Nullable
+	public SemanticContext evalPrecedence(Recognizer<?,?> parser, RuleContext outerContext) {
+		return this;
+	}
+
     public static class Predicate extends SemanticContext {
         public final int ruleIndex;
        	public final int predIndex;
@@ -114,6 +128,43 @@ public abstract class SemanticContext {
         }
     }
 
+	public static class PrecedencePredicate extends SemanticContext {
+		public final int precedence;
+
+		public PrecedencePredicate(int precedence) {
+			this.precedence = precedence;
+		}
+
+		@Override
+		public boolean eval(Recognizer<?,?> parser, RuleContext outerContext) {
+			return parser.precpred(outerContext, precedence);
+		}
+
+		@Override
+		public SemanticContext evalPrecedence(Recognizer<?,?> parser, RuleContext outerContext) {
+			if ( parser.precpred(outerContext, precedence) ) return NONE;
+			return null;
+		}
+
+		@Override
+		public int hashCode() {
+			return 31 + precedence;
+		}
+
+		@Override
+		public boolean equals(Object obj) {
+			if ( !(obj instanceof PrecedencePredicate) ) return false;
+			if ( this == obj ) return true;
+			PrecedencePredicate other = (PrecedencePredicate)obj;
+			return this.precedence == other.precedence;
+		}
+
+		@Override
+		public String toString() {
+			return "{"+precedence+">=prec}?";
+		}
+	}
+
     public static class AND extends SemanticContext {
 		@NotNull public final SemanticContext[] opnds;
 
@@ -148,6 +199,17 @@ public abstract class SemanticContext {
 			return true;
         }
 
+		@Override
+		public SemanticContext evalPrecedence(Recognizer<?,?> parser, RuleContext outerContext) {
+			SemanticContext result = NONE;
+			for (SemanticContext opnd : opnds) {
+				SemanticContext evaluated = opnd.evalPrecedence(parser, outerContext);
+				if ( evaluated==null ) return null; // one false operand makes the AND false
+				result = and(result, evaluated);
+			}
+			return result;
+		}
+
 		@Override
 		public String toString() {
 			return Utils.join(Arrays.asList(opnds).iterator(), "&&");
@@ -188,6 +250,17 @@ public abstract class SemanticContext {
 			return false;
         }
 
+		@Override
+		public SemanticContext evalPrecedence(Recognizer<?,?> parser, RuleContext outerContext) {
+			SemanticContext result = null;
+			for (SemanticContext opnd : opnds) {
+				SemanticContext evaluated = opnd.evalPrecedence(parser, outerContext);
+				if ( evaluated==NONE ) return NONE; // one true operand makes the OR true
+				result = or(result, evaluated);
+			}
+			return result;
+		}
+
         @Override
         public String toString() {
 			return Utils.join(Arrays.asList(opnds).iterator(), "||");
@@ -33,6 +33,13 @@ package org.antlr.v4.runtime.atn;
 public final class StarLoopEntryState extends DecisionState {
 	public StarLoopbackState loopBackState;
 
+	/** Is this the operator loop decision of a left-recursive rule? Its DFA
+	 *  keeps a separate start state per precedence level.
+	 *
+	 *  @see org.antlr.v4.runtime.dfa.DFA#isPrecedenceDfa
+	 */
+	public boolean precedenceRuleDecision;
+
 	@Override
 	public int getStateType() {
 		return STAR_LOOP_ENTRY;
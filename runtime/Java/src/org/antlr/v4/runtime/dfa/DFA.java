@@ -30,14 +30,17 @@
 package org.antlr.v4.runtime.dfa;
 
 import org.antlr.v4.runtime.TokenStream;
+import org.antlr.v4.runtime.atn.ATNConfigSet;
 import org.antlr.v4.runtime.atn.ATNState;
 import org.antlr.v4.runtime.atn.DecisionState;
 import org.antlr.v4.runtime.atn.ParserATNSimulator;
+import org.antlr.v4.runtime.atn.StarLoopEntryState;
 import org.antlr.v4.runtime.atn.Transition;
 import org.antlr.v4.runtime.misc.NotNull;
 import org.antlr.v4.runtime.misc.Nullable;
 
 import java.util.ArrayList;
+import java.util.Arrays;
 import java.util.HashSet;
 import java.util.LinkedHashMap;
 import java.util.List;
@@ -68,9 +71,75 @@ public class DFA {
 		this(atnStartState, 0);
 	}
 
+	/** {@code true} if this DFA is for the operator loop of a left-recursive
+	 *  rule. Such a DFA has no single start state; {@link #s0} is a
+	 *  placeholder whose {@link DFAState#edges edges} hold the start state
+	 *  for each precedence level.
+	 */
+	private final boolean precedenceDfa;
+
 	public DFA(@NotNull DecisionState atnStartState, int decision) {
 		this.atnStartState = atnStartState;
 		this.decision = decision;
+
+		boolean precedenceDfa = false;
+		if ( atnStartState instanceof StarLoopEntryState &&
+			 ((StarLoopEntryState)atnStartState).precedenceRuleDecision )
+		{
+			precedenceDfa = true;
+			DFAState precedenceState = new DFAState(new ATNConfigSet());
+			precedenceState.edges = new DFAState[0];
+			precedenceState.isAcceptState = false;
+			precedenceState.requiresFullContext = false;
+			this.s0 = precedenceState;
+		}
+		this.precedenceDfa = precedenceDfa;
+	}
+
+	/** Is this DFA keyed on the parser's precedence level?
+	 *
+	 *  @see #getPrecedenceStartState
+	 *  @see #setPrecedenceStartState
+	 */
+	public final boolean isPrecedenceDfa() {
+		return precedenceDfa;
+	}
+
+	/** Get the start state for a specific precedence value.
+	 *
+	 *  @return the start state for {@code precedence}, or {@code null} if
+	 *  none has been computed yet
+	 *  @throws IllegalStateException if this is not a precedence DFA
+	 */
+	@Nullable
+	public final DFAState getPrecedenceStartState(int precedence) {
+		if ( !isPrecedenceDfa() ) {
+			throw new IllegalStateException("Only precedence DFAs may contain a precedence start state.");
+		}
+
+		DFAState[] edges = s0.edges;
+		if ( precedence < 0 || precedence >= edges.length ) return null;
+		return edges[precedence];
+	}
+
+	/** Set the start state for a specific precedence value.
+	 *
+	 *  @throws IllegalStateException if this is not a precedence DFA
+	 */
+	public final void setPrecedenceStartState(int precedence, @NotNull DFAState startState) {
+		if ( !isPrecedenceDfa() ) {
+			throw new IllegalStateException("Only precedence DFAs may contain a precedence start state.");
+		}
+		if ( precedence < 0 ) return;
+
+		// synchronization on s0 here is ok. when the DFA is turned into a
+		// precedence DFA, s0 will be initialized once and not updated again
+		synchronized (s0) {
+			if ( precedence >= s0.edges.length ) {
+				s0.edges = Arrays.copyOf(s0.edges, precedence + 1);
+			}
+			s0.edges[precedence] = startState;
+		}
 	}
 
 	public List<Set<ATNState>> getATNStatesAlongPath(ParserATNSimulator atn,
@@ -0,0 +1,89 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime.misc;
+
+import org.antlr.v4.runtime.CommonToken;
+import org.antlr.v4.runtime.Parser;
+import org.antlr.v4.runtime.Recognizer;
+import org.antlr.v4.runtime.Token;
+import org.antlr.v4.runtime.tree.Tree;
+import org.antlr.v4.runtime.tree.Trees;
+
+import java.io.IOException;
+import java.io.Writer;
+import java.util.Arrays;
+import java.util.List;
+
+/** Write tokens and parse trees in a stable, line-oriented format for
+ *  debugging. Unlike {@link Trees#toStringTree}, nothing is buffered,
+ *  so these also work on very large inputs.
+ */
+public class DebugDump {
+	/** Write one token per line, as {@link CommonToken#toString(Recognizer)}
+	 *  formats it; {@code r} may be the lexer, to name channels.
+	 */
+	public static void dumpTokens(@NotNull List<? extends Token> tokens,
+								  @Nullable Recognizer<?, ?> r,
+								  @NotNull Writer out)
+		throws IOException
+	{
+		for (Token t : tokens) {
+			if ( t instanceof CommonToken ) out.write(((CommonToken)t).toString(r));
+			else out.write(String.valueOf(t));
+			out.write('\n');
+		}
+		out.flush();
+	}
+
+	/** Write one node per line, indented two spaces per level, using
+	 *  {@link Trees#getNodeText} with whitespace escaped.
+	 */
+	public static void dumpTree(@NotNull Tree t, @Nullable Parser recog,
+								@NotNull Writer out)
+		throws IOException
+	{
+		String[] ruleNames = recog != null ? recog.getRuleNames() : null;
+		List<String> ruleNamesList = ruleNames != null ? Arrays.asList(ruleNames) : null;
+		dumpTree(t, ruleNamesList, 0, out);
+		out.flush();
+	}
+
+	protected static void dumpTree(@NotNull Tree t, @Nullable List<String> ruleNames,
+								   int depth, @NotNull Writer out)
+		throws IOException
+	{
+		for (int i=0; i<depth; i++) out.write("  ");
+		out.write(Utils.escapeWhitespace(Trees.getNodeText(t, ruleNames), false));
+		out.write('\n');
+		for (int i=0; i<t.getChildCount(); i++) {
+			dumpTree(t.getChild(i), ruleNames, depth+1, out);
+		}
+	}
+}
@@ -259,6 +259,39 @@ public class IntervalSet implements IntSet {
 		return this.and(((IntervalSet)other).complement(COMPLETE_CHAR_SET));
 	}
 
+	/** Return a new set with the elements in exactly one of this and other. */
+	public IntervalSet xor(IntSet other) {
+		IntervalSet both = this.and(other);
+		IntervalSet result = new IntervalSet();
+		// unlike subtract, keep elements outside the char range such as EOF
+		for (Interval I : this.or(other).intervals) {
+			int a = I.a;
+			for (Interval common : both.intervals) {
+				if ( common.b<a ) continue;
+				if ( common.a>I.b ) break;
+				if ( common.a>a ) result.add(a, common.a-1);
+				a = common.b+1;
+			}
+			if ( a<=I.b ) result.add(a, I.b);
+		}
+		return result;
+	}
+
+	/** Is every element of this set also in other? */
+	public boolean isSubsetOf(IntSet other) {
+		return isNil() || this.and(other).equals(this);
+	}
+
+	/** Is every element of other also in this set? */
+	public boolean isSupersetOf(IntSet other) {
+		return ((IntervalSet)other).isSubsetOf(this);
+	}
+
+	/** Do this set and other have any element in common? */
+	public boolean overlaps(IntSet other) {
+		return !and(other).isNil();
+	}
+
 	@Override
 	public IntervalSet or(IntSet a) {
 		IntervalSet o = new IntervalSet();
@@ -344,31 +377,24 @@ public class IntervalSet implements IntSet {
     /** Is el in any range of this set? */
     @Override
     public boolean contains(int el) {
-		int n = intervals.size();
-		for (int i = 0; i < n; i++) {
-			Interval I = intervals.get(i);
-			int a = I.a;
-			int b = I.b;
-			if ( el<a ) {
-				break; // list is sorted and el is before this interval; not here
+		// intervals are sorted and disjoint; binary search keeps large
+		// character classes (e.g., Unicode letters) cheap to test
+		int lo = 0;
+		int hi = intervals.size() - 1;
+		while ( lo<=hi ) {
+			int mid = (lo + hi) >>> 1;
+			Interval I = intervals.get(mid);
+			if ( el<I.a ) {
+				hi = mid - 1;
+			}
+			else if ( el>I.b ) {
+				lo = mid + 1;
 			}
-			if ( el>=a && el<=b ) {
+			else {
 				return true; // found in this interval
 			}
 		}
 		return false;
-/*
-		for (ListIterator iter = intervals.listIterator(); iter.hasNext();) {
-            Interval I = (Interval) iter.next();
-            if ( el<I.a ) {
-                break; // list is sorted and el is before this interval; not here
-            }
-            if ( el>=I.a && el<=I.b ) {
-                return true; // found in this interval
-            }
-        }
-        return false;
-        */
     }
 
     /** return true if this set has no members */
@@ -0,0 +1,106 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime.misc;
+
+import org.antlr.v4.runtime.CharStream;
+
+/** Maps char offsets into some text to line and charPositionInLine, and
+ *  back, using the same conventions as tokens: lines start at 1, positions
+ *  within a line start at 0, and a line ends after its '\n'. Lookups use
+ *  binary search over the offsets where each line starts.
+ */
+public class LineIndex {
+	/** lineStarts.get(i) is the offset of the first char on line i+1 */
+	@NotNull
+	protected final IntegerList lineStarts = new IntegerList();
+
+	protected final int size;
+
+	public LineIndex(@NotNull CharSequence text) {
+		lineStarts.add(0);
+		for (int i=0; i<text.length(); i++) {
+			if ( text.charAt(i)=='\n' ) lineStarts.add(i+1);
+		}
+		size = text.length();
+	}
+
+	/** Index all of {@code input}, which must support getText over its
+	 *  whole range (e.g., {@link org.antlr.v4.runtime.ANTLRInputStream}).
+	 */
+	public LineIndex(@NotNull CharStream input) {
+		this(input.getText(Interval.of(0, input.size()-1)));
+	}
+
+	/** Number of chars indexed. */
+	public int size() {
+		return size;
+	}
+
+	public int getNumberOfLines() {
+		return lineStarts.size();
+	}
+
+	/** Return the line containing {@code offset}; an offset equal to
+	 *  {@link #size} (EOF) is on the last line.
+	 */
+	public int getLine(int offset) {
+		checkOffset(offset);
+		int i = lineStarts.binarySearch(offset);
+		if ( i<0 ) i = -i-2; // insertion point minus one: the line starting before offset
+		return i+1;
+	}
+
+	public int getCharPositionInLine(int offset) {
+		return offset - lineStarts.get(getLine(offset)-1);
+	}
+
+	/** Return the offset of {@code charPositionInLine} on {@code line}.
+	 *  The position may run up to the end of the line, including its
+	 *  newline, but not past it.
+	 */
+	public int getOffset(int line, int charPositionInLine) {
+		if ( line<1 || line>lineStarts.size() ) {
+			throw new IllegalArgumentException("line "+line+" out of range 1.."+lineStarts.size());
+		}
+		int start = lineStarts.get(line-1);
+		int end = line<lineStarts.size() ? lineStarts.get(line)-1 : size;
+		if ( charPositionInLine<0 || start+charPositionInLine>end ) {
+			throw new IllegalArgumentException("charPositionInLine "+charPositionInLine+
+											   " out of range on line "+line);
+		}
+		return start+charPositionInLine;
+	}
+
+	protected void checkOffset(int offset) {
+		if ( offset<0 || offset>size ) {
+			throw new IndexOutOfBoundsException("offset "+offset+" out of range 0.."+size);
+		}
+	}
+}
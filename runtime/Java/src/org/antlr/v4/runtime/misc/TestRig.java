@@ -32,13 +32,19 @@ package org.antlr.v4.runtime.misc;
 
 import org.antlr.v4.runtime.ANTLRInputStream;
 import org.antlr.v4.runtime.CharStream;
+import org.antlr.v4.runtime.CommonToken;
 import org.antlr.v4.runtime.CommonTokenStream;
 import org.antlr.v4.runtime.DiagnosticErrorListener;
 import org.antlr.v4.runtime.Lexer;
 import org.antlr.v4.runtime.Parser;
 import org.antlr.v4.runtime.ParserRuleContext;
+import org.antlr.v4.runtime.RuleTimingListener;
 import org.antlr.v4.runtime.TokenStream;
+import org.antlr.v4.runtime.atn.ATN;
+import org.antlr.v4.runtime.atn.ATNSimulator;
 import org.antlr.v4.runtime.atn.PredictionMode;
+import org.antlr.v4.runtime.dfa.DFA;
+import org.antlr.v4.runtime.dfa.DFAState;
 
 import javax.print.PrintException;
 import java.io.FileInputStream;
@@ -50,7 +56,10 @@ import java.lang.reflect.Constructor;
 import java.lang.reflect.InvocationTargetException;
 import java.lang.reflect.Method;
 import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.LinkedList;
 import java.util.List;
+import java.util.Map;
 
 /** Run a lexer/parser combo, optionally printing tree string or generating
  *  postscript file. Optionally taking input file.
@@ -58,10 +67,23 @@ import java.util.List;
  *  $ java org.antlr.v4.runtime.misc.TestRig GrammarName startRuleName
  *        [-tree]
  *        [-tokens] [-gui] [-ps file.ps]
- *        [-trace]
+ *        [-trace] [-timing] [-stats]
  *        [-diagnostics]
  *        [-SLL]
+ *        [-compare expected-output-file]
  *        [input-filename(s)]
+ *
+ *  With -compare, the -tokens/-tree output is also checked against the
+ *  given file; any difference is printed to stderr as a line diff and the
+ *  rig exits with status 1. -compare without -tokens or -tree is an
+ *  error.
+ *
+ *  With -timing, a per-rule breakdown of parse time (see
+ *  {@link RuleTimingListener}) is printed to stderr after the parse.
+ *
+ *  With -stats, the rule and decision counts of the grammar are printed to
+ *  stderr after all input has been parsed, followed by the size of each
+ *  decision's DFA and the deepest lookahead it has recorded so far.
  */
 public class TestRig {
 	public static final String LEXER_START_RULE_NAME = "tokens";
@@ -74,15 +96,23 @@ public class TestRig {
 	protected String psFile = null;
 	protected boolean showTokens = false;
 	protected boolean trace = false;
+	protected boolean timing = false;
+	protected boolean stats = false;
 	protected boolean diagnostics = false;
 	protected String encoding = null;
 	protected boolean SLL = false;
+	protected String compareFile = null;
+	/** Largest LCS table {@link #diff} will build (about 16MB) */
+	public static final int MAX_DIFF_CELLS = 1 << 22;
+
+	/** Everything printed by -tokens and -tree, for -compare */
+	protected final StringBuilder output = new StringBuilder();
 
 	public TestRig(String[] args) throws Exception {
 		if ( args.length < 2 ) {
 			System.err.println("java org.antlr.v4.runtime.misc.TestRig GrammarName startRuleName\n" +
 							   "  [-tokens] [-tree] [-gui] [-ps file.ps] [-encoding encodingname]\n" +
-							   "  [-trace] [-diagnostics] [-SLL]\n"+
+							   "  [-trace] [-timing] [-stats] [-diagnostics] [-SLL] [-compare expected-output-file]\n"+
 							   "  [input-filename(s)]");
 			System.err.println("Use startRuleName='tokens' if GrammarName is a lexer grammar.");
 			System.err.println("Omitting input-filename makes rig read from stdin.");
@@ -112,6 +142,12 @@ public class TestRig {
 			else if ( arg.equals("-trace") ) {
 				trace = true;
 			}
+			else if ( arg.equals("-timing") ) {
+				timing = true;
+			}
+			else if ( arg.equals("-stats") ) {
+				stats = true;
+			}
 			else if ( arg.equals("-SLL") ) {
 				SLL = true;
 			}
@@ -126,6 +162,14 @@ public class TestRig {
 				encoding = args[i];
 				i++;
 			}
+			else if ( arg.equals("-compare") ) {
+				if ( i>=args.length ) {
+					System.err.println("missing filename on -compare");
+					return;
+				}
+				compareFile = args[i];
+				i++;
+			}
 			else if ( arg.equals("-ps") ) {
 				if ( i>=args.length ) {
 					System.err.println("missing filename on -ps");
@@ -139,7 +183,14 @@ public class TestRig {
 
 	public static void main(String[] args) throws Exception {
 		TestRig testRig = new TestRig(args);
+		if ( testRig.compareFile!=null && !testRig.showTokens && !testRig.printTree ) {
+			System.err.println("-compare needs -tokens or -tree to have something to compare");
+			System.exit(1);
+		}
 		testRig.process();
+		if ( testRig.compareFile!=null && !testRig.compareOutput() ) {
+			System.exit(1);
+		}
 	}
 
 	public void process() throws Exception {
@@ -188,6 +239,7 @@ public class TestRig {
 			}
 
 			process(lexer, parserClass, parser, is, r);
+			if ( stats ) printStats(lexer, parser);
 			return;
 		}
 		for (String inputFile : inputFiles) {
@@ -208,6 +260,68 @@ public class TestRig {
 			}
 			process(lexer, parserClass, parser, is, r);
 		}
+		if ( stats ) printStats(lexer, parser);
+	}
+
+	/** Print rule and decision counts, then one line per DFA: its size
+	 *  and depth (the most symbols any of its states is from the start
+	 *  state, a lower bound on the lookahead prediction has needed).
+	 */
+	protected void printStats(Lexer lexer, Parser parser) {
+		ATN atn = lexer.getATN();
+		System.err.println("lexer: "+atn.ruleToStartState.length+" rules, "+
+						   atn.modeToStartState.size()+" modes");
+		DFA[] modeDFA = lexer.getInterpreter().decisionToDFA;
+		for (int m=0; m<modeDFA.length; m++) {
+			System.err.println("  mode "+m+": "+modeDFA[m].states.size()+" DFA states");
+		}
+		if ( parser==null ) return;
+
+		atn = parser.getATN();
+		String[] ruleNames = parser.getRuleNames();
+		System.err.println("parser: "+atn.ruleToStartState.length+" rules, "+
+						   atn.getNumberOfDecisions()+" decisions");
+		for (DFA dfa : parser.getInterpreter().decisionToDFA) {
+			if ( dfa.states.isEmpty() ) continue; // never predicted
+			System.err.println("  decision "+dfa.decision+" ("+
+							   ruleNames[dfa.atnStartState.ruleIndex]+"): "+
+							   dfa.states.size()+" DFA states, depth "+getDepth(dfa));
+		}
+	}
+
+	protected int getDepth(DFA dfa) {
+		if ( dfa.s0==null ) return 0;
+		Map<DFAState, Integer> depth = new HashMap<DFAState, Integer>();
+		LinkedList<DFAState> work = new LinkedList<DFAState>();
+		if ( dfa.isPrecedenceDfa() ) {
+			// s0's edges lead to the start state for each precedence level
+			if ( dfa.s0.edges!=null ) {
+				for (DFAState start : dfa.s0.edges) {
+					if ( start!=null && !depth.containsKey(start) ) {
+						depth.put(start, 0);
+						work.add(start);
+					}
+				}
+			}
+		}
+		else {
+			depth.put(dfa.s0, 0);
+			work.add(dfa.s0);
+		}
+		int max = 0;
+		while ( !work.isEmpty() ) {
+			DFAState s = work.removeFirst();
+			int d = depth.get(s);
+			max = Math.max(max, d);
+			if ( s.edges==null ) continue;
+			for (DFAState t : s.edges) {
+				if ( t!=null && t!=ATNSimulator.ERROR && !depth.containsKey(t) ) {
+					depth.put(t, d+1);
+					work.add(t);
+				}
+			}
+		}
+		return max;
 	}
 
 	protected void process(Lexer lexer, Class<? extends Parser> parserClass, Parser parser, InputStream is, Reader r) throws IOException, IllegalAccessException, InvocationTargetException, PrintException {
@@ -220,7 +334,12 @@ public class TestRig {
 
 			if ( showTokens ) {
 				for (Object tok : tokens.getTokens()) {
-					System.out.println(tok);
+					if ( tok instanceof CommonToken ) {
+						println(((CommonToken)tok).toString(lexer));
+					}
+					else {
+						println(String.valueOf(tok));
+					}
 				}
 			}
 
@@ -241,13 +360,18 @@ public class TestRig {
 
 			parser.setTokenStream(tokens);
 			parser.setTrace(trace);
+			RuleTimingListener timer = null;
+			if ( timing ) {
+				timer = new RuleTimingListener(parser);
+				parser.addParseListener(timer);
+			}
 
 			try {
 				Method startRule = parserClass.getMethod(startRuleName);
 				ParserRuleContext tree = (ParserRuleContext)startRule.invoke(parser, (Object[])null);
 
 				if ( printTree ) {
-					System.out.println(tree.toStringTree(parser));
+					println(tree.toStringTree(parser));
 				}
 				if ( gui ) {
 					tree.inspect(parser);
@@ -255,6 +379,10 @@ public class TestRig {
 				if ( psFile!=null ) {
 					tree.save(parser, psFile); // Generate postscript
 				}
+				if ( timer!=null ) {
+					System.err.print(timer);
+					parser.removeParseListener(timer);
+				}
 			}
 			catch (NoSuchMethodException nsme) {
 				System.err.println("No method for rule "+startRuleName+" or it has arguments");
@@ -265,4 +393,111 @@ public class TestRig {
 			if ( is!=null ) is.close();
 		}
 	}
+
+	protected void println(String line) {
+		System.out.println(line);
+		output.append(line).append('\n');
+	}
+
+	/** Compare what -tokens/-tree printed with the contents of
+	 *  {@link #compareFile}, reporting any differences to stderr
+	 *  (see {@link #diff}).
+	 *
+	 *  @return true if they match
+	 */
+	public boolean compareOutput() throws IOException {
+		Reader r;
+		if ( encoding!=null ) {
+			r = new InputStreamReader(new FileInputStream(compareFile), encoding);
+		}
+		else {
+			r = new InputStreamReader(new FileInputStream(compareFile));
+		}
+		StringBuilder expected = new StringBuilder();
+		try {
+			char[] buf = new char[4096];
+			int n;
+			while ( (n = r.read(buf)) >= 0 ) {
+				expected.append(buf, 0, n);
+			}
+		}
+		finally {
+			r.close();
+		}
+
+		String[] expectedLines = expected.toString().replace("\r\n", "\n").split("\n");
+		String[] foundLines = output.toString().split("\n");
+		List<String> diffs = diff(expectedLines, foundLines);
+		if ( diffs.isEmpty() ) return true;
+		System.err.println("output differs from "+compareFile+":");
+		for (String d : diffs) System.err.println(d);
+		return false;
+	}
+
+	/** Line diff of {@code expected} against {@code found}, aligned on
+	 *  their longest common subsequence so that an inserted or missing
+	 *  line shows up once instead of shifting everything after it. Each
+	 *  run of changes starts with "line N:", N being the line in
+	 *  {@code expected}, followed by its "- " (expected only) and "+ "
+	 *  (found only) lines. Empty if the two are the same.
+	 *
+	 *  The common prefix and suffix are skipped first. If what is left
+	 *  would need an LCS table of more than {@link #MAX_DIFF_CELLS}
+	 *  entries, only the first differing line is reported.
+	 */
+	public static List<String> diff(String[] expected, String[] found) {
+		int m = expected.length;
+		int n = found.length;
+		int lo = 0;
+		while ( lo<m && lo<n && expected[lo].equals(found[lo]) ) lo++;
+		while ( m>lo && n>lo && expected[m-1].equals(found[n-1]) ) {
+			m--;
+			n--;
+		}
+
+		List<String> diffs = new ArrayList<String>();
+		if ( lo==m && lo==n ) return diffs;
+
+		if ( (long)(m-lo+1) * (n-lo+1) > MAX_DIFF_CELLS ) {
+			diffs.add("line "+(lo+1)+":");
+			if ( lo<m ) diffs.add("- "+expected[lo]);
+			if ( lo<n ) diffs.add("+ "+found[lo]);
+			diffs.add("(too many differences to align; only the first is shown)");
+			return diffs;
+		}
+
+		// lcs[i][j] is the LCS length of expected[lo+i..m) and found[lo+j..n)
+		int[][] lcs = new int[m-lo+1][n-lo+1];
+		for (int i = m-1; i >= lo; i--) {
+			for (int j = n-1; j >= lo; j--) {
+				int a = i-lo;
+				int b = j-lo;
+				if ( expected[i].equals(found[j]) ) lcs[a][b] = lcs[a+1][b+1] + 1;
+				else lcs[a][b] = Math.max(lcs[a+1][b], lcs[a][b+1]);
+			}
+		}
+
+		boolean inHunk = false;
+		int i = lo;
+		int j = lo;
+		while ( i<m || j<n ) {
+			if ( i<m && j<n && expected[i].equals(found[j]) ) {
+				inHunk = false;
+				i++;
+				j++;
+				continue;
+			}
+			if ( !inHunk ) {
+				diffs.add("line "+(i+1)+":");
+				inHunk = true;
+			}
+			if ( j>=n || (i<m && lcs[i-lo+1][j-lo] >= lcs[i-lo][j-lo+1]) ) {
+				diffs.add("- "+expected[i++]);
+			}
+			else {
+				diffs.add("+ "+found[j++]);
+			}
+		}
+		return diffs;
+	}
 }
@@ -0,0 +1,202 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime.misc;
+
+import org.antlr.v4.runtime.CharStream;
+import org.antlr.v4.runtime.Lexer;
+import org.antlr.v4.runtime.Token;
+
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/** Turn the tokens of any lexer into styled spans of a document, for
+ *  editors. A token's style is, in order of preference, the style set for
+ *  its type, the style set for its channel, or one derived from the
+ *  vocabulary: "keyword" for literals like {@code 'while'}, "operator"
+ *  for other literals like {@code '+='}, and otherwise the token name.
+ *
+ *  After an edit, {@link #rehighlight} lexes again from a little before
+ *  the edit and stops as soon as it is back in step with the previous
+ *  spans. This assumes the lexer's state between tokens is captured by
+ *  its mode stack and that no token looks ahead past the token after it.
+ */
+public class TokenHighlighter {
+	public static class Span {
+		/** Char offsets of the token, inclusive. */
+		public final int start, stop;
+		public final int tokenType;
+		@NotNull
+		public final String style;
+
+		/** Where the lexer started looking for this token, before any
+		 *  skipped input, and whether it was in the default mode with an
+		 *  empty mode stack there so lexing can resume from that point.
+		 */
+		protected final int lexStart;
+		protected final boolean resumable;
+
+		public Span(int start, int stop, int tokenType, @NotNull String style,
+					int lexStart, boolean resumable)
+		{
+			this.start = start;
+			this.stop = stop;
+			this.tokenType = tokenType;
+			this.style = style;
+			this.lexStart = lexStart;
+			this.resumable = resumable;
+		}
+
+		protected Span shift(int delta) {
+			return new Span(start+delta, stop+delta, tokenType, style, lexStart+delta, resumable);
+		}
+
+		protected boolean sameAs(Span other, int delta) {
+			return start==other.start+delta && stop==other.stop+delta &&
+				   lexStart==other.lexStart+delta &&
+				   tokenType==other.tokenType && style.equals(other.style) &&
+				   resumable==other.resumable;
+		}
+
+		@Override
+		public String toString() {
+			return start+".."+stop+":"+style;
+		}
+	}
+
+	@NotNull
+	protected final Lexer lexer;
+
+	protected final Map<Integer, String> typeStyles = new HashMap<Integer, String>();
+	protected final Map<Integer, String> channelStyles = new HashMap<Integer, String>();
+
+	public TokenHighlighter(@NotNull Lexer lexer) {
+		this.lexer = lexer;
+	}
+
+	public void setTokenStyle(int tokenType, @NotNull String style) {
+		typeStyles.put(tokenType, style);
+	}
+
+	public void setChannelStyle(int channel, @NotNull String style) {
+		channelStyles.put(channel, style);
+	}
+
+	@NotNull
+	public String getStyle(@NotNull Token t) {
+		String style = typeStyles.get(t.getType());
+		if ( style!=null ) return style;
+		style = channelStyles.get(t.getChannel());
+		if ( style!=null ) return style;
+
+		String[] tokenNames = lexer.getTokenNames();
+		if ( tokenNames==null || t.getType()<0 || t.getType()>=tokenNames.length ) {
+			return String.valueOf(t.getType());
+		}
+		String name = tokenNames[t.getType()];
+		if ( name.length()>2 && name.startsWith("'") && name.endsWith("'") ) {
+			String literal = name.substring(1, name.length()-1);
+			return isWord(literal) ? "keyword" : "operator";
+		}
+		return name;
+	}
+
+	/** Highlight all of {@code input}. */
+	@NotNull
+	public List<Span> highlight(@NotNull CharStream input) {
+		return lex(input, 0, new ArrayList<Span>(), null, 0, 0);
+	}
+
+	/** Update {@code previous}, the spans of a document before an edit, for
+	 *  {@code input}, the document after it. The edit replaced
+	 *  {@code removedLength} chars at {@code editStart} with
+	 *  {@code insertedLength} new ones.
+	 */
+	@NotNull
+	public List<Span> rehighlight(@NotNull List<Span> previous, @NotNull CharStream input,
+								  int editStart, int removedLength, int insertedLength)
+	{
+		// skip spans wholly before the edit, keeping the one just before it
+		// since it may grow into the edit (e.g., an identifier)
+		int k = 0;
+		while ( k<previous.size() && previous.get(k).stop+1<editStart ) k++;
+		int r = k-1;
+		while ( r>=0 && !previous.get(r).resumable ) r--;
+		if ( r<0 ) return highlight(input);
+
+		List<Span> spans = new ArrayList<Span>(previous.subList(0, r));
+		return lex(input, previous.get(r).lexStart, spans, previous,
+				   editStart+insertedLength, insertedLength-removedLength);
+	}
+
+	/** Lex {@code input} from {@code index}, appending to {@code spans}.
+	 *  Once past {@code resyncAfter}, stop lexing at the first span that
+	 *  matches one of {@code previous} moved by {@code delta}, and copy the
+	 *  rest of {@code previous} instead.
+	 */
+	protected List<Span> lex(@NotNull CharStream input, int index, @NotNull List<Span> spans,
+							 @Nullable List<Span> previous, int resyncAfter, int delta)
+	{
+		lexer.setInputStream(input);
+		input.seek(index);
+		int j = 0; // next candidate in previous
+		while ( true ) {
+			int lexStart = input.index();
+			boolean resumable = lexer._mode==Lexer.DEFAULT_MODE && lexer._modeStack.isEmpty();
+			Token t = lexer.nextToken();
+			if ( t.getType()==Token.EOF ) break;
+			if ( t.getStopIndex()<t.getStartIndex() ) continue;
+
+			Span span = new Span(t.getStartIndex(), t.getStopIndex(), t.getType(),
+								 getStyle(t), lexStart, resumable);
+			spans.add(span);
+
+			if ( previous!=null && resumable && lexStart>=resyncAfter ) {
+				while ( j<previous.size() && previous.get(j).lexStart+delta<lexStart ) j++;
+				if ( j<previous.size() && span.sameAs(previous.get(j), delta) ) {
+					for (Span p : previous.subList(j+1, previous.size())) {
+						spans.add(p.shift(delta));
+					}
+					break;
+				}
+			}
+		}
+		return spans;
+	}
+
+	protected static boolean isWord(String s) {
+		for (int i = 0; i < s.length(); i++) {
+			char c = s.charAt(i);
+			if ( !(Character.isLetterOrDigit(c) || c=='_') ) return false;
+		}
+		return s.length()>0;
+	}
+}
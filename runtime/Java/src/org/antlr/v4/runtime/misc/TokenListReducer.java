@@ -0,0 +1,128 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime.misc;
+
+import org.antlr.v4.runtime.ListTokenSource;
+import org.antlr.v4.runtime.Token;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/** Shrink a token list that triggers a problem (a syntax error, an
+ *  ambiguity report, an exception, a hang caught by a timeout) to a
+ *  minimal list that still triggers it, using delta debugging (ddmin):
+ *  repeatedly retry with chunks of the list and with the list minus a
+ *  chunk, keeping whichever still fails. The result is 1-minimal:
+ *  removing any single token makes the problem go away. The search is
+ *  deterministic, so the same input always reduces to the same output.
+ *
+ *  Typically {@link Failure#reproduces} re-parses the tokens through a
+ *  {@link ListTokenSource} and reports whether the problem recurred.
+ */
+public class TokenListReducer {
+	public interface Failure {
+		/** Return true if parsing {@code tokens} still shows the problem. */
+		boolean reproduces(@NotNull List<? extends Token> tokens);
+	}
+
+	@NotNull
+	protected final Failure failure;
+
+	/** Number of times {@link #failure} was consulted by {@link #reduce}. */
+	protected int tests;
+
+	public TokenListReducer(@NotNull Failure failure) {
+		this.failure = failure;
+	}
+
+	@NotNull
+	public <T extends Token> List<T> reduce(@NotNull List<T> tokens) {
+		tests = 0;
+		if ( !test(tokens) ) {
+			throw new IllegalArgumentException("input does not reproduce the failure");
+		}
+
+		List<T> current = new ArrayList<T>(tokens);
+		int n = 2;
+		while ( current.size()>=2 ) {
+			List<List<T>> chunks = split(current, n);
+			boolean reduced = false;
+			for (List<T> chunk : chunks) {
+				if ( test(chunk) ) {
+					current = chunk;
+					n = 2;
+					reduced = true;
+					break;
+				}
+			}
+			if ( !reduced && n>2 ) {
+				for (int i=0; i<chunks.size(); i++) {
+					List<T> complement = new ArrayList<T>();
+					for (int j=0; j<chunks.size(); j++) {
+						if ( j!=i ) complement.addAll(chunks.get(j));
+					}
+					if ( test(complement) ) {
+						current = complement;
+						n = Math.max(n-1, 2);
+						reduced = true;
+						break;
+					}
+				}
+			}
+			if ( !reduced ) {
+				if ( n>=current.size() ) break; // single tokens tried; done
+				n = Math.min(n*2, current.size());
+			}
+		}
+		return current;
+	}
+
+	public int getNumberOfTests() {
+		return tests;
+	}
+
+	protected boolean test(@NotNull List<? extends Token> tokens) {
+		tests++;
+		return failure.reproduces(tokens);
+	}
+
+	/** Split list into n chunks whose sizes differ by at most one. */
+	@NotNull
+	protected static <T> List<List<T>> split(@NotNull List<T> list, int n) {
+		List<List<T>> chunks = new ArrayList<List<T>>(n);
+		int start = 0;
+		for (int i=0; i<n; i++) {
+			int end = start + (list.size()-start)/(n-i);
+			chunks.add(new ArrayList<T>(list.subList(start, end)));
+			start = end;
+		}
+		return chunks;
+	}
+}
@@ -0,0 +1,191 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime.misc;
+
+import org.antlr.v4.runtime.Token;
+import org.antlr.v4.runtime.Vocabulary;
+
+import java.io.BufferedReader;
+import java.io.FileReader;
+import java.io.IOException;
+import java.io.Reader;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/** The token types of a grammar as read from the {@code .tokens} file the
+ *  tool writes next to the generated code. Each line is either
+ *  {@code NAME=type} or {@code 'literal'=type}; a token type may have one
+ *  of each.
+ */
+public class TokenVocabulary implements Vocabulary {
+	/** Prefix of the names the tool makes up for literals used in parser
+	 *  rules, such as {@code T__0} for {@code '+'}.
+	 */
+	public static final String AUTO_GENERATED_TOKEN_NAME_PREFIX = "T__";
+
+	/** A vocabulary that names no token types but EOF. */
+	public static final TokenVocabulary EMPTY_VOCABULARY =
+		new TokenVocabulary(new ArrayList<String>(), new ArrayList<String>(),
+							new HashMap<String, Integer>());
+
+	private final List<String> symbolicNames;
+	private final List<String> literalNames;
+	private final Map<String, Integer> nameToType;
+
+	/** Vocabularies are immutable; make one with {@link #load}. */
+	private TokenVocabulary(@NotNull List<String> symbolicNames,
+							@NotNull List<String> literalNames,
+							@NotNull Map<String, Integer> nameToType)
+	{
+		this.symbolicNames = Collections.unmodifiableList(symbolicNames);
+		this.literalNames = Collections.unmodifiableList(literalNames);
+		this.nameToType = Collections.unmodifiableMap(nameToType);
+	}
+
+	@NotNull
+	public static TokenVocabulary load(@NotNull String fileName) throws IOException {
+		Reader r = new FileReader(fileName);
+		try {
+			return load(r, fileName);
+		}
+		finally {
+			r.close();
+		}
+	}
+
+	/** Read a vocabulary from {@code r}; {@code sourceName} identifies it in
+	 *  error messages.
+	 *
+	 *  @throws IllegalArgumentException if a line is not a valid token
+	 *  definition; the message includes the line number.
+	 */
+	@NotNull
+	public static TokenVocabulary load(@NotNull Reader r, String sourceName) throws IOException {
+		List<String> symbolicNames = new ArrayList<String>();
+		List<String> literalNames = new ArrayList<String>();
+		Map<String, Integer> nameToType = new HashMap<String, Integer>();
+		BufferedReader br = new BufferedReader(r);
+		int lineNum = 0;
+		for (String line = br.readLine(); line!=null; line = br.readLine()) {
+			lineNum++;
+			String def = line.trim();
+			if ( def.length()==0 ) continue;
+
+			// a literal such as '=' may itself contain '='
+			int eq = def.lastIndexOf('=');
+			String name = eq>0 ? def.substring(0, eq).trim() : "";
+			int ttype = -1;
+			if ( eq>0 ) {
+				try {
+					ttype = Integer.parseInt(def.substring(eq+1).trim());
+				}
+				catch (NumberFormatException nfe) {
+					// reported below
+				}
+			}
+
+			boolean literal = name.startsWith("'");
+			if ( name.length()==0 || ttype<Token.MIN_USER_TOKEN_TYPE ||
+				 (literal && (name.length()<3 || !name.endsWith("'"))) )
+			{
+				throw new IllegalArgumentException(sourceName+":"+lineNum+
+												   ": bad token definition: "+line);
+			}
+			List<String> names = literal ? literalNames : symbolicNames;
+			while ( names.size()<=ttype ) names.add(null);
+			names.set(ttype, name);
+			nameToType.put(name, ttype);
+		}
+		return new TokenVocabulary(symbolicNames, literalNames, nameToType);
+	}
+
+	@Override
+	public int getMaxTokenType() {
+		return Math.max(Math.max(symbolicNames.size(), literalNames.size()) - 1, 0);
+	}
+
+	/** Return the type of token {@code name}, either a symbolic name or a
+	 *  quoted literal, or {@link Token#INVALID_TYPE} if undefined.
+	 */
+	public int getTokenType(@NotNull String name) {
+		Integer ttype = nameToType.get(name);
+		if ( ttype==null ) return Token.INVALID_TYPE;
+		return ttype;
+	}
+
+	@Override
+	@Nullable
+	public String getSymbolicName(int ttype) {
+		if ( ttype==Token.EOF ) return "EOF";
+		if ( ttype<0 || ttype>=symbolicNames.size() ) return null;
+		return symbolicNames.get(ttype);
+	}
+
+	@Override
+	@Nullable
+	public String getLiteralName(int ttype) {
+		if ( ttype<0 || ttype>=literalNames.size() ) return null;
+		return literalNames.get(ttype);
+	}
+
+	/** Return the name to show for {@code ttype}, as in a generated
+	 *  recognizer's token names: the literal for tokens named by the tool,
+	 *  otherwise the symbolic name, else the literal, else the number.
+	 */
+	@Override
+	@NotNull
+	public String getDisplayName(int ttype) {
+		String symbolic = getSymbolicName(ttype);
+		String literal = getLiteralName(ttype);
+		if ( symbolic!=null &&
+			 !(symbolic.startsWith(AUTO_GENERATED_TOKEN_NAME_PREFIX) && literal!=null) )
+		{
+			return symbolic;
+		}
+		if ( literal!=null ) return literal;
+		return String.valueOf(ttype);
+	}
+
+	/** Return display names indexed by token type, suitable for
+	 *  {@link org.antlr.v4.runtime.Recognizer#getTokenNames}.
+	 */
+	@NotNull
+	public String[] getTokenNames() {
+		String[] tokenNames = new String[getMaxTokenType()+1];
+		tokenNames[0] = "<INVALID>";
+		for (int ttype = Token.MIN_USER_TOKEN_TYPE; ttype < tokenNames.length; ttype++) {
+			tokenNames[ttype] = getDisplayName(ttype);
+		}
+		return tokenNames;
+	}
+}
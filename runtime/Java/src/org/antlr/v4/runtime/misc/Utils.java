@@ -37,6 +37,7 @@ import java.io.BufferedWriter;
 import java.io.FileWriter;
 import java.io.IOException;
 import java.io.Writer;
+import java.util.Arrays;
 import java.util.Collection;
 import java.util.Iterator;
 
@@ -53,6 +54,10 @@ public class Utils {
         return buf.toString();
     }
 
+	public static <T> String join(T[] array, String separator) {
+		return join(Arrays.asList(array).iterator(), separator);
+	}
+
 	public static int numNonnull(Object[] data) {
 		int n = 0;
 		if ( data == null ) return n;
@@ -31,6 +31,7 @@
 package org.antlr.v4.runtime.tree;
 
 import org.antlr.v4.runtime.Token;
+import org.antlr.v4.runtime.misc.NotNull;
 
 /** Represents a token that was consumed during resynchronization
  *  rather than during a valid match operation. For example,
@@ -39,10 +40,32 @@ import org.antlr.v4.runtime.Token;
  *  upon no viable alternative exceptions.
  */
 public class ErrorNodeImpl extends TerminalNodeImpl implements ErrorNode {
+	/** What {@link #getText} returns for an error node. */
+	public enum TextMode {
+		/** the offending token's text, as for any terminal */
+		TOKEN,
+		/** the token text wrapped as {@code <error: text>} */
+		MARKER,
+		/** the empty string, so error tokens drop out of rule text */
+		EMPTY
+	}
+
+	@NotNull
+	public TextMode textMode = TextMode.TOKEN;
+
 	public ErrorNodeImpl(Token token) {
 		super(token);
 	}
 
+	@Override
+	public String getText() {
+		switch ( textMode ) {
+			case MARKER : return "<error: "+symbol.getText()+">";
+			case EMPTY : return "";
+			default : return super.getText();
+		}
+	}
+
 	@Override
 	public <T> T accept(ParseTreeVisitor<? extends T> visitor) {
 		return visitor.visitErrorNode(this);
@@ -0,0 +1,106 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.runtime.tree;
+
+import org.antlr.v4.runtime.ParserRuleContext;
+import org.antlr.v4.runtime.Token;
+import org.antlr.v4.runtime.misc.Interval;
+import org.antlr.v4.runtime.misc.NotNull;
+import org.antlr.v4.runtime.misc.Nullable;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/** An entry in a document outline computed by {@link Trees#getOutline}:
+ *  a context for one of the outlined rules, the token that names it, and
+ *  the entries for outlined contexts nested within it.
+ */
+public class OutlineNode {
+	@NotNull
+	public final ParserRuleContext ctx;
+
+	/** The first token of the name type within {@link #ctx}, outside of
+	 *  nested entries; null if there is none.
+	 */
+	@Nullable
+	public final Token nameToken;
+
+	@NotNull
+	public final List<OutlineNode> children = new ArrayList<OutlineNode>();
+
+	public OutlineNode(@NotNull ParserRuleContext ctx, @Nullable Token nameToken) {
+		this.ctx = ctx;
+		this.nameToken = nameToken;
+	}
+
+	public int getRuleIndex() {
+		return ctx.getRuleIndex();
+	}
+
+	/** Return the char range of {@link #ctx} in the input, or
+	 *  {@link Interval#INVALID} if it matched no tokens.
+	 */
+	@NotNull
+	public Interval getSpan() {
+		if ( ctx.start==null || ctx.stop==null ||
+			 ctx.stop.getTokenIndex()<ctx.start.getTokenIndex() )
+		{
+			return Interval.INVALID;
+		}
+		return Interval.of(ctx.start.getStartIndex(), ctx.stop.getStopIndex());
+	}
+
+	/** Print as {@code ruleName name [children]}. */
+	public String toString(@Nullable List<String> ruleNames) {
+		StringBuilder buf = new StringBuilder();
+		int ruleIndex = getRuleIndex();
+		if ( ruleNames!=null && ruleIndex>=0 && ruleIndex<ruleNames.size() ) {
+			buf.append(ruleNames.get(ruleIndex));
+		}
+		else {
+			buf.append(ruleIndex);
+		}
+		if ( nameToken!=null ) buf.append(' ').append(nameToken.getText());
+		if ( !children.isEmpty() ) {
+			buf.append(" [");
+			for (int i = 0; i < children.size(); i++) {
+				if ( i>0 ) buf.append(", ");
+				buf.append(children.get(i).toString(ruleNames));
+			}
+			buf.append(']');
+		}
+		return buf.toString();
+	}
+
+	@Override
+	public String toString() {
+		return toString(null);
+	}
+}
@@ -31,7 +31,9 @@
 package org.antlr.v4.runtime.tree;
 
 import org.antlr.v4.runtime.Parser;
+import org.antlr.v4.runtime.ParserRuleContext;
 import org.antlr.v4.runtime.Token;
+import org.antlr.v4.runtime.misc.Interval;
 import org.antlr.v4.runtime.misc.NotNull;
 import org.antlr.v4.runtime.misc.Nullable;
 import org.antlr.v4.runtime.misc.Utils;
@@ -44,6 +46,7 @@ import java.util.ArrayList;
 import java.util.Arrays;
 import java.util.Collections;
 import java.util.List;
+import java.util.Set;
 
 /** A set of utility routines useful for all kinds of ANTLR trees. */
 public class Trees {
@@ -101,21 +104,39 @@ public class Trees {
 	 *  parse trees and extract data appropriately.
 	 */
 	public static String toStringTree(@NotNull Tree t, @Nullable List<String> ruleNames) {
-		String s = Utils.escapeWhitespace(getNodeText(t, ruleNames), false);
-		if ( t.getChildCount()==0 ) return s;
 		StringBuilder buf = new StringBuilder();
-		buf.append("(");
-		s = Utils.escapeWhitespace(getNodeText(t, ruleNames), false);
-		buf.append(s);
-		buf.append(' ');
-		for (int i = 0; i<t.getChildCount(); i++) {
-			if ( i>0 ) buf.append(' ');
-			buf.append(toStringTree(t.getChild(i), ruleNames));
+		try {
+			toStringTree(t, ruleNames, buf);
+		}
+		catch (IOException ioe) {
+			throw new IllegalStateException(ioe); // StringBuilder never throws
 		}
-		buf.append(")");
 		return buf.toString();
 	}
 
+	/** Like {@link #toStringTree(Tree, List)} but append the LISP form
+	 *  to {@code out} as it goes instead of building a string per subtree,
+	 *  so large trees can be streamed to a writer or preallocated buffer.
+	 */
+	public static void toStringTree(@NotNull Tree t, @Nullable List<String> ruleNames,
+									@NotNull Appendable out)
+		throws IOException
+	{
+		String s = Utils.escapeWhitespace(getNodeText(t, ruleNames), false);
+		if ( t.getChildCount()==0 ) {
+			out.append(s);
+			return;
+		}
+		out.append('(');
+		out.append(s);
+		out.append(' ');
+		for (int i = 0; i<t.getChildCount(); i++) {
+			if ( i>0 ) out.append(' ');
+			toStringTree(t.getChild(i), ruleNames, out);
+		}
+		out.append(')');
+	}
+
 	public static String getNodeText(@NotNull Tree t, @Nullable Parser recog) {
 		String[] ruleNames = recog != null ? recog.getRuleNames() : null;
 		List<String> ruleNamesList = ruleNames != null ? Arrays.asList(ruleNames) : null;
@@ -163,6 +184,102 @@ public class Trees {
 		return ancestors;
 	}
 
+	/** Return the line range, as {@code startLine..stopLine}, of every
+	 *  context in {@code t} for one of {@code ruleIndexes} that spans more
+	 *  than one line, in pre-order; e.g., for folding in an editor.
+	 */
+	@NotNull
+	public static List<Interval> getFoldingRanges(@NotNull ParseTree t,
+												  @NotNull Set<Integer> ruleIndexes)
+	{
+		List<Interval> ranges = new ArrayList<Interval>();
+		getFoldingRanges(t, ruleIndexes, ranges);
+		return ranges;
+	}
+
+	private static void getFoldingRanges(ParseTree t, Set<Integer> ruleIndexes,
+										 List<Interval> ranges)
+	{
+		if ( t instanceof ParserRuleContext ) {
+			ParserRuleContext ctx = (ParserRuleContext)t;
+			if ( ruleIndexes.contains(ctx.getRuleIndex()) && ctx.start!=null && ctx.stop!=null &&
+				 ctx.stop.getTokenIndex()>=ctx.start.getTokenIndex() )
+			{
+				int startLine = ctx.start.getLine();
+				int stopLine = getLastLine(ctx.stop);
+				if ( stopLine>startLine ) ranges.add(Interval.of(startLine, stopLine));
+			}
+		}
+		for (int i = 0; i < t.getChildCount(); i++) {
+			getFoldingRanges(t.getChild(i), ruleIndexes, ranges);
+		}
+	}
+
+	/** The line {@code t} ends on, which is later than its start line if
+	 *  its text spans lines (e.g., a block comment).
+	 */
+	private static int getLastLine(Token t) {
+		int line = t.getLine();
+		String text = t.getText();
+		if ( text!=null ) {
+			for (int i = 0; i < text.length(); i++) {
+				if ( text.charAt(i)=='\n' ) line++;
+			}
+		}
+		return line;
+	}
+
+	/** Return a document outline of {@code t}: an entry for each context
+	 *  for one of {@code ruleIndexes}, named by its first token of type
+	 *  {@code nameTokenType} and nested like the contexts themselves.
+	 */
+	@NotNull
+	public static List<OutlineNode> getOutline(@NotNull ParseTree t,
+											   @NotNull Set<Integer> ruleIndexes,
+											   int nameTokenType)
+	{
+		List<OutlineNode> outline = new ArrayList<OutlineNode>();
+		getOutline(t, ruleIndexes, nameTokenType, outline);
+		return outline;
+	}
+
+	private static void getOutline(ParseTree t, Set<Integer> ruleIndexes,
+								   int nameTokenType, List<OutlineNode> outline)
+	{
+		if ( t instanceof ParserRuleContext &&
+			 ruleIndexes.contains(((ParserRuleContext)t).getRuleIndex()) )
+		{
+			ParserRuleContext ctx = (ParserRuleContext)t;
+			OutlineNode node = new OutlineNode(ctx, findNameToken(ctx, ruleIndexes, nameTokenType));
+			outline.add(node);
+			outline = node.children;
+		}
+		for (int i = 0; i < t.getChildCount(); i++) {
+			getOutline(t.getChild(i), ruleIndexes, nameTokenType, outline);
+		}
+	}
+
+	/** Find the first token of type {@code ttype} under {@code t} without
+	 *  looking inside nested outlined contexts, which are named separately.
+	 */
+	@Nullable
+	private static Token findNameToken(ParseTree t, Set<Integer> ruleIndexes, int ttype) {
+		for (int i = 0; i < t.getChildCount(); i++) {
+			ParseTree c = t.getChild(i);
+			if ( c instanceof TerminalNode && !(c instanceof ErrorNode) ) {
+				Token token = ((TerminalNode)c).getSymbol();
+				if ( token.getType()==ttype ) return token;
+			}
+			else if ( c instanceof ParserRuleContext &&
+					  !ruleIndexes.contains(((ParserRuleContext)c).getRuleIndex()) )
+			{
+				Token token = findNameToken(c, ruleIndexes, ttype);
+				if ( token!=null ) return token;
+			}
+		}
+		return null;
+	}
+
 	private Trees() {
 	}
 }
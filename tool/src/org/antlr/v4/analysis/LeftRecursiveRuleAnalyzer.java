@@ -264,6 +264,8 @@ public class LeftRecursiveRuleAnalyzer extends LeftRecursiveRuleWalker {
 		for (int alt : opPrecRuleAlts.keySet()) {
 			LeftRecursiveRuleAltInfo altInfo = opPrecRuleAlts.get(alt);
 			ST altST = recRuleTemplates.getInstanceOf("recRuleAlt");
+			altST.add("precOption", LeftRecursiveRuleTransformer.PRECEDENCE_OPTION_NAME);
+			altST.add("opPrec", precedence(alt));
 			ST predST = codegenTemplates.getInstanceOf("recRuleAltPredicate");
 			predST.add("opPrec", precedence(alt));
 			predST.add("ruleName", ruleName);
@@ -67,6 +67,11 @@ import java.util.List;
  *  MODIFIES grammar AST in place.
  */
 public class LeftRecursiveRuleTransformer {
+	/** Element option on the predicate guarding each operator alternative,
+	 *  as in {@code {3 >= $_p}?<p=3>}; marks it as a precedence predicate.
+	 */
+	public static final String PRECEDENCE_OPTION_NAME = "p";
+
 	public GrammarRootAST ast;
 	public Collection<Rule> rules;
 	public Grammar g;
@@ -40,8 +40,10 @@ import org.antlr.v4.runtime.atn.AtomTransition;
 import org.antlr.v4.runtime.atn.BlockStartState;
 import org.antlr.v4.runtime.atn.DecisionState;
 import org.antlr.v4.runtime.atn.LoopEndState;
+import org.antlr.v4.runtime.atn.PrecedencePredicateTransition;
 import org.antlr.v4.runtime.atn.PredicateTransition;
 import org.antlr.v4.runtime.atn.RangeTransition;
+import org.antlr.v4.runtime.atn.RuleStartState;
 import org.antlr.v4.runtime.atn.RuleTransition;
 import org.antlr.v4.runtime.atn.SetTransition;
 import org.antlr.v4.runtime.atn.Transition;
@@ -110,6 +112,7 @@ public class ATNSerializer {
 
 		// dump states, count edges and collect sets while doing so
 		IntegerList nonGreedyStates = new IntegerList();
+		IntegerList precedenceStates = new IntegerList();
 		data.add(atn.states.size());
 		for (ATNState s : atn.states) {
 			if ( s==null ) { // might be optimized away
@@ -122,6 +125,10 @@ public class ATNSerializer {
 				nonGreedyStates.add(s.stateNumber);
 			}
 
+			if (s instanceof RuleStartState && ((RuleStartState)s).isPrecedenceRule) {
+				precedenceStates.add(s.stateNumber);
+			}
+
 			data.add(stateType);
 			data.add(s.ruleIndex);
 			if ( s.getStateType() == ATNState.LOOP_END ) {
@@ -152,6 +159,12 @@ public class ATNSerializer {
 			data.add(nonGreedyStates.get(i));
 		}
 
+		// precedence states
+		data.add(precedenceStates.size());
+		for (int i = 0; i < precedenceStates.size(); i++) {
+			data.add(precedenceStates.get(i));
+		}
+
 		int nrules = atn.ruleToStartState.length;
 		data.add(nrules);
 		for (int r=0; r<nrules; r++) {
@@ -213,6 +226,7 @@ public class ATNSerializer {
 						trg = ((RuleTransition)t).followState.stateNumber;
 						arg1 = ((RuleTransition)t).target.stateNumber;
 						arg2 = ((RuleTransition)t).ruleIndex;
+						arg3 = ((RuleTransition)t).precedence;
 						break;
 					case Transition.PREDICATE :
 						PredicateTransition pt = (PredicateTransition)t;
@@ -220,6 +234,10 @@ public class ATNSerializer {
 						arg2 = pt.predIndex;
 						arg3 = pt.isCtxDependent ? 1 : 0 ;
 						break;
+					case Transition.PRECEDENCE :
+						PrecedencePredicateTransition ppt = (PrecedencePredicateTransition)t;
+						arg1 = ppt.precedence;
+						break;
 					case Transition.RANGE :
 						arg1 = ((RangeTransition)t).from;
 						arg2 = ((RangeTransition)t).to;
@@ -310,6 +328,10 @@ public class ATNSerializer {
 		for (int i = 0; i < numNonGreedyStates; i++) {
 			int stateNumber = ATNSimulator.toInt(data[p++]);
 		}
+		int numPrecedenceStates = ATNSimulator.toInt(data[p++]);
+		for (int i = 0; i < numPrecedenceStates; i++) {
+			int stateNumber = ATNSimulator.toInt(data[p++]);
+		}
 		int nrules = ATNSimulator.toInt(data[p++]);
 		for (int i=0; i<nrules; i++) {
 			int s = ATNSimulator.toInt(data[p++]);
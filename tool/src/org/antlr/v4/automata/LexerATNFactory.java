@@ -35,6 +35,7 @@ import org.antlr.v4.codegen.CodeGenerator;
 import org.antlr.v4.misc.CharSupport;
 import org.antlr.v4.parse.ANTLRParser;
 import org.antlr.v4.runtime.IntStream;
+import org.antlr.v4.runtime.Lexer;
 import org.antlr.v4.runtime.atn.ATN;
 import org.antlr.v4.runtime.atn.ATNState;
 import org.antlr.v4.runtime.atn.ActionTransition;
@@ -61,8 +62,13 @@ import java.util.Set;
 
 public class LexerATNFactory extends ParserATNFactory {
 	public STGroup codegenTemplates;
+
+	/** options {caseInsensitive=true;}: every char matches either case */
+	public boolean caseInsensitive;
+
 	public LexerATNFactory(LexerGrammar g) {
 		super(g);
+		caseInsensitive = "true".equals(g.getOptionString("caseInsensitive"));
 		// use codegen to get correct language templates for lexer commands
 		String language = g.getOptionString("language");
 		CodeGenerator gen = new CodeGenerator(g.tool, null, language);
@@ -161,7 +167,12 @@ public class LexerATNFactory extends ParserATNFactory {
 		ATNState right = newState(b);
 		int t1 = CharSupport.getCharValueFromGrammarCharLiteral(a.getText());
 		int t2 = CharSupport.getCharValueFromGrammarCharLiteral(b.getText());
-		left.addTransition(new  RangeTransition(right, t1, t2));
+		if ( caseInsensitive ) {
+			left.addTransition(setTransition(right, caseClosure(IntervalSet.of(t1, t2))));
+		}
+		else {
+			left.addTransition(new  RangeTransition(right, t1, t2));
+		}
 		a.atnState = left;
 		b.atnState = left;
 		return new Handle(left, right);
@@ -197,19 +208,12 @@ public class LexerATNFactory extends ParserATNFactory {
 										   g.fileName, t.getToken(), t.getText());
 			}
 		}
+		if ( caseInsensitive ) set = caseClosure(set);
 		if ( invert ) {
 			left.addTransition(new NotSetTransition(right, set));
 		}
 		else {
-			Transition transition;
-			if (set.getIntervals().size() == 1) {
-				Interval interval = set.getIntervals().get(0);
-				transition = new RangeTransition(right, interval.a, interval.b);
-			} else {
-				transition = new SetTransition(right, set);
-			}
-
-			left.addTransition(transition);
+			left.addTransition(setTransition(right, set));
 		}
 		associatedAST.atnState = left;
 		return new Handle(left, right);
@@ -230,7 +234,10 @@ public class LexerATNFactory extends ParserATNFactory {
 		ATNState right = null;
 		for (int i=0; i<n; i++) {
 			right = newState(stringLiteralAST);
-			prev.addTransition(new AtomTransition(right, chars.charAt(i)));
+			IntervalSet c = IntervalSet.of(chars.charAt(i));
+			if ( caseInsensitive ) c = caseClosure(c);
+			if ( c.size()==1 ) prev.addTransition(new AtomTransition(right, chars.charAt(i)));
+			else prev.addTransition(new SetTransition(right, c));
 			prev = right;
 		}
 		stringLiteralAST.atnState = left;
@@ -243,11 +250,34 @@ public class LexerATNFactory extends ParserATNFactory {
 		ATNState left = newState(charSetAST);
 		ATNState right = newState(charSetAST);
 		IntervalSet set = getSetFromCharSetLiteral(charSetAST);
+		if ( caseInsensitive ) set = caseClosure(set);
 		left.addTransition(new SetTransition(right, set));
 		charSetAST.atnState = left;
 		return new Handle(left, right);
 	}
 
+	/** A range transition if set is one interval, else a set transition */
+	protected Transition setTransition(ATNState target, IntervalSet set) {
+		if ( set.getIntervals().size() == 1 ) {
+			Interval interval = set.getIntervals().get(0);
+			return new RangeTransition(target, interval.a, interval.b);
+		}
+		return new SetTransition(target, set);
+	}
+
+	/** Add the upper and lower case forms of each char in set */
+	public IntervalSet caseClosure(IntervalSet set) {
+		IntervalSet closure = new IntervalSet(set);
+		for (Interval I : set.getIntervals()) {
+			for (int c=I.a; c<=I.b; c++) {
+				if ( c<Lexer.MIN_CHAR_VALUE || c>Lexer.MAX_CHAR_VALUE ) continue;
+				closure.add(Character.toLowerCase((char)c));
+				closure.add(Character.toUpperCase((char)c));
+			}
+		}
+		return closure;
+	}
+
 	public IntervalSet getSetFromCharSetLiteral(GrammarAST charSetAST) {
 		String chars = charSetAST.getText();
 		chars = chars.substring(1, chars.length()-1);
@@ -341,6 +341,14 @@ public class Target {
 		return "\\u"+hex;
 	}
 
+	/** Maximum number of chars in one serialized ATN string constant. A Java
+	 *  class file stores a string constant in at most 65535 bytes of modified
+	 *  UTF-8, and each char takes up to 3 bytes; longer ATNs are split.
+	 */
+	public int getSerializedATNSegmentLimit() {
+		return 65535 / 3;
+	}
+
 	public String getLoopLabel(GrammarAST ast) {
 		return "loop"+ ast.token.getTokenIndex();
 	}
@@ -36,8 +36,10 @@ import org.antlr.v4.tool.Rule;
 import org.antlr.v4.tool.ast.ActionAST;
 import org.antlr.v4.tool.ast.AltAST;
 
+import java.util.HashMap;
 import java.util.HashSet;
 import java.util.List;
+import java.util.Map;
 import java.util.Set;
 
 /** A model object representing a parse tree listener file.
@@ -48,6 +50,8 @@ public class ListenerFile extends OutputFile {
 	public String grammarName;
 	public String parserName;
 	public Set<String> listenerNames = new HashSet<String>();
+	/** Rule doc comments by listener/visitor name, for generated javadoc */
+	public Map<String, String> listenerDocs = new HashMap<String, String>();
 
 	@ModelElement public Action header;
 
@@ -61,11 +65,13 @@ public class ListenerFile extends OutputFile {
 			if ( labels!=null ) {
 				for (Triple<Integer,AltAST,String> pair : labels) {
 					listenerNames.add(pair.c);
+					listenerDocs.put(pair.c, r.getDocComment());
 				}
 			}
 			else {
 				// only add rule context if no labels
 				listenerNames.add(r.name);
+				listenerDocs.put(r.name, r.getDocComment());
 			}
 		}
 		ActionAST ast = g.namedActions.get("header");
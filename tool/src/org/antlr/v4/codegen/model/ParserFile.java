@@ -40,6 +40,7 @@ import java.util.Map;
 /** */
 public class ParserFile extends OutputFile {
 	public String genPackage; // from -package cmd-line
+	public String contextSuperClass; // rule contexts extend this, not ParserRuleContext
 	@ModelElement public Parser parser;
 	@ModelElement public Map<String, Action> namedActions;
 
@@ -52,5 +53,6 @@ public class ParserFile extends OutputFile {
 			namedActions.put(name, new Action(factory, ast));
 		}
 		genPackage = factory.getGrammar().tool.genPackage;
+		contextSuperClass = g.getOptionString("contextSuperClass");
 	}
 }
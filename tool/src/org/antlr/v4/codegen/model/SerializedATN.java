@@ -51,4 +51,14 @@ public class SerializedATN extends OutputModelObject {
 		}
 //		System.out.println(ATNSerializer.getDecoded(factory.getGrammar(), atn));
 	}
+
+	/** The serialized chars in chunks small enough for one string constant */
+	public List<List<String>> getSegments() {
+		List<List<String>> segments = new ArrayList<List<String>>();
+		int segmentLimit = factory.getGenerator().target.getSerializedATNSegmentLimit();
+		for (int i = 0; i < serialized.size(); i += segmentLimit) {
+			segments.add(serialized.subList(i, Math.min(i + segmentLimit, serialized.size())));
+		}
+		return segments;
+	}
 }
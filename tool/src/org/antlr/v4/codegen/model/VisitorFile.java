@@ -36,8 +36,10 @@ import org.antlr.v4.tool.Rule;
 import org.antlr.v4.tool.ast.ActionAST;
 import org.antlr.v4.tool.ast.AltAST;
 
+import java.util.HashMap;
 import java.util.HashSet;
 import java.util.List;
+import java.util.Map;
 import java.util.Set;
 
 public class VisitorFile extends OutputFile {
@@ -45,6 +47,8 @@ public class VisitorFile extends OutputFile {
 	public String grammarName;
 	public String parserName;
 	public Set<String> visitorNames = new HashSet<String>();
+	/** Rule doc comments by listener/visitor name, for generated javadoc */
+	public Map<String, String> visitorDocs = new HashMap<String, String>();
 
 	@ModelElement public Action header;
 
@@ -58,11 +62,13 @@ public class VisitorFile extends OutputFile {
 			if ( labels!=null ) {
 				for (Triple<Integer,AltAST,String> pair : labels) {
 					visitorNames.add(pair.c);
+					visitorDocs.put(pair.c, r.getDocComment());
 				}
 			}
 			else {
 				// if labels, must label all. no need for generic rule visitor then
 				visitorNames.add(r.name);
+				visitorDocs.put(r.name, r.getDocComment());
 			}
 		}
 		ActionAST ast = g.namedActions.get("header");
@@ -50,6 +50,7 @@ import java.util.List;
 public class StructDecl extends Decl {
 	public String derivedFromName; // rule name or label name
 	public boolean provideCopyFrom;
+	public String docComment; // from the rule, if any
 	@ModelElement public OrderedHashSet<Decl> attrs = new OrderedHashSet<Decl>();
 	@ModelElement public OrderedHashSet<Decl> getters = new OrderedHashSet<Decl>();
 	@ModelElement public Collection<Attribute> ctorAttrs;
@@ -62,6 +63,7 @@ public class StructDecl extends Decl {
 		addDispatchMethods(r);
 		derivedFromName = r.name;
 		provideCopyFrom = r.hasAltSpecificContexts();
+		docComment = r.getDocComment();
 	}
 
 	public void addDispatchMethods(Rule r) {
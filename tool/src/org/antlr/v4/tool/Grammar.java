@@ -38,6 +38,7 @@ import org.antlr.runtime.tree.TreeVisitor;
 import org.antlr.runtime.tree.TreeVisitorAction;
 import org.antlr.runtime.tree.TreeWizard;
 import org.antlr.v4.Tool;
+import org.antlr.v4.analysis.LeftRecursiveRuleTransformer;
 import org.antlr.v4.misc.CharSupport;
 import org.antlr.v4.misc.OrderedHashMap;
 import org.antlr.v4.misc.Utils;
@@ -78,6 +79,8 @@ public class Grammar implements AttributeResolver {
 	static {
 		parserOptions.add("superClass");
 		parserOptions.add("TokenLabelType");
+		parserOptions.add("contextSuperClass");
+		parserOptions.add("caseInsensitive");
 		parserOptions.add("tokenVocab");
 		parserOptions.add("language");
 	}
@@ -104,6 +107,7 @@ public class Grammar implements AttributeResolver {
 
 	public static final Set<String> semPredOptions = new HashSet<String>();
 	static {
+		semPredOptions.add(LeftRecursiveRuleTransformer.PRECEDENCE_OPTION_NAME);
 		semPredOptions.add("fail");
 	}
 
@@ -111,6 +115,7 @@ public class Grammar implements AttributeResolver {
 	static {
 		doNotCopyOptionsToLexer.add("superClass");
 		doNotCopyOptionsToLexer.add("TokenLabelType");
+		doNotCopyOptionsToLexer.add("contextSuperClass");
 		doNotCopyOptionsToLexer.add("tokenVocab");
 	}
 
@@ -30,6 +30,8 @@
 
 package org.antlr.v4.tool;
 
+import org.antlr.runtime.tree.Tree;
+import org.antlr.v4.parse.ANTLRParser;
 import org.antlr.v4.runtime.misc.Triple;
 import org.antlr.v4.tool.ast.ActionAST;
 import org.antlr.v4.tool.ast.AltAST;
@@ -319,6 +321,15 @@ public class Rule implements AttributeResolver {
         return Grammar.grammarAndLabelRefTypeToScope.get(grammarLabelKey);
     }
 
+	/** Get the doc comment in front of the rule, delimiters included,
+	 *  or null if there isn't one.
+	 */
+	public String getDocComment() {
+		Tree doc = ast.getFirstChildWithType(ANTLRParser.DOC_COMMENT);
+		if ( doc==null ) return null;
+		return doc.getText();
+	}
+
 	public boolean isFragment() {
 		if ( modifiers==null ) return false;
 		for (GrammarAST a : modifiers) {
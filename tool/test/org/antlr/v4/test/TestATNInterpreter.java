@@ -35,6 +35,7 @@ import org.antlr.v4.runtime.NoViableAltException;
 import org.antlr.v4.runtime.atn.ATN;
 import org.antlr.v4.runtime.atn.ATNState;
 import org.antlr.v4.runtime.atn.BlockStartState;
+import org.antlr.v4.runtime.atn.DecisionState;
 import org.antlr.v4.runtime.atn.LexerATNSimulator;
 import org.antlr.v4.runtime.dfa.DFA;
 import org.antlr.v4.runtime.misc.IntegerList;
@@ -376,6 +377,101 @@ public class TestATNInterpreter extends BaseTest {
 		checkMatchedAlt(lg, g, "((34))c", 2);
 	}
 
+	@Test public void testFirstAndFollowOfRule() throws Exception {
+		Grammar g = new Grammar(
+			"parser grammar T;\n"+
+			"tokens {A,B,C,D}\n" +
+			"s : a C | b ;\n" +
+			"a : A? ;\n" +
+			"b : B a D? ;");
+		ATN atn = createATN(g, false);
+		String[] tokenNames = g.getTokenDisplayNames();
+		int a = g.getRule("a").index;
+		int b = g.getRule("b").index;
+		assertEquals("{<EPSILON>, A}", atn.firstOfRule(a).toString(tokenNames));
+		assertEquals("B", atn.firstOfRule(b).toString(tokenNames));
+		// b can end s, which nothing invokes
+		assertEquals("{<EPSILON>, C, D}", atn.followOfRule(a, null).toString(tokenNames));
+		assertEquals("<EPSILON>", atn.followOfRule(b, null).toString(tokenNames));
+	}
+
+	@Test public void testFollowOfRuleIncludesEOF() throws Exception {
+		Grammar g = new Grammar(
+			"parser grammar T;\n"+
+			"tokens {A,B}\n" +
+			"s : a EOF | B a B ;\n" +
+			"a : A ;");
+		ATN atn = createATN(g, false);
+		String[] tokenNames = g.getTokenDisplayNames();
+		int a = g.getRule("a").index;
+		assertEquals("{<EOF>, B}", atn.followOfRule(a, null).toString(tokenNames));
+	}
+
+	@Test public void testRuleDependencyDOT() throws Exception {
+		Grammar g = new Grammar(
+			"parser grammar T;\n"+
+			"tokens {A}\n" +
+			"s : a b ;\n" +
+			"a : A | '(' s ')' ;\n" +
+			"b : a* ;");
+		ATN atn = createATN(g, false);
+		String expecting =
+			"digraph G {\n" +
+			"rankdir=LR;\n" +
+			"  r0 [label=\"s\"];\n" +
+			"  r1 [label=\"a\"];\n" +
+			"  r2 [label=\"b\"];\n" +
+			"  r0->r1;\n" +
+			"  r0->r2;\n" +
+			"  r1->r0;\n" +
+			"  r2->r1;\n" +
+			"}\n";
+		assertEquals(expecting, atn.getRuleDependencyDOT(g.rules.keySet().toArray(new String[0])));
+	}
+
+	@Test public void testPruneUnreachableRules() throws Exception {
+		Grammar g = new Grammar(
+			"parser grammar T;\n"+
+			"tokens {A,B}\n" +
+			"s : a ;\n" +
+			"a : A a? ;\n" +
+			"t : b s ;\n" +
+			"b : B | B B ;");
+		ATN atn = createATN(g, false);
+		int s = g.getRule("s").index;
+		int a = g.getRule("a").index;
+		int b = g.getRule("b").index;
+		assertEquals("{0, 1}", atn.getReachableRules(s).toString());
+		int before = 0;
+		for (ATNState state : atn.states) {
+			if ( state!=null ) before++;
+		}
+		int removed = atn.pruneUnreachableRules(s);
+		int after = 0;
+		for (ATNState state : atn.states) {
+			if ( state!=null ) {
+				after++;
+				assertTrue(state.ruleIndex==s || state.ruleIndex==a);
+			}
+		}
+		assertEquals(before - removed, after);
+		assertNotNull(atn.ruleToStartState[a]);
+		assertNull(atn.ruleToStartState[b]);
+		assertNull(atn.ruleToStopState[b]);
+		for (DecisionState d : atn.decisionToState) {
+			if ( d!=null ) assertTrue(d.ruleIndex==s || d.ruleIndex==a);
+		}
+		// no edges lead into removed states, including a's follow link into t
+		for (ATNState state : atn.states) {
+			if ( state==null ) continue;
+			for (int i = 0; i < state.getNumberOfTransitions(); i++) {
+				assertNotNull(atn.states.get(state.transition(i).target.stateNumber));
+			}
+		}
+		// what s can match is unchanged
+		assertEquals("A", atn.firstOfRule(s).toString(g.getTokenDisplayNames()));
+	}
+
 	public void checkMatchedAlt(LexerGrammar lg, final Grammar g,
 								String inputString,
 								int expected)
@@ -30,8 +30,11 @@
 
 package org.antlr.v4.test;
 
+import org.antlr.v4.automata.ATNSerializer;
 import org.antlr.v4.runtime.ANTLRInputStream;
 import org.antlr.v4.runtime.CharStream;
+import org.antlr.v4.runtime.LexerInterpreter;
+import org.antlr.v4.runtime.Token;
 import org.antlr.v4.runtime.atn.ATN;
 import org.antlr.v4.runtime.atn.ATNState;
 import org.antlr.v4.runtime.misc.Utils;
@@ -308,6 +311,41 @@ public class TestATNLexerInterpreter extends BaseTest {
 		checkLexerMatches(lg, "a", expecting);
 	}
 
+	@Test public void testRuntimeLexerInterpreter() throws Exception {
+		LexerGrammar lg = new LexerGrammar(
+			"lexer grammar L;\n"+
+			"A : 'a' ;\n"+
+			"B : 'b'+ ;\n"+
+			"WS : ' ' ;\n");
+		ATN atn = createATN(lg, true);
+		LexerInterpreter lexer =
+			new LexerInterpreter("L.g4", atn, lg.getTokenDisplayNames(),
+								 lg.rules.keySet().toArray(new String[0]),
+								 lg.modes.keySet().toArray(new String[0]),
+								 null, new ANTLRInputStream("a bb"));
+		StringBuilder buf = new StringBuilder();
+		for (Token t : lexer.getAllTokens()) {
+			if ( buf.length()>0 ) buf.append(", ");
+			buf.append(t.getType()).append(':').append(t.getText());
+		}
+		assertEquals("1:a, 3: , 2:bb", buf.toString());
+		assertEquals("DEFAULT_MODE", lexer.getModeNames()[0]);
+	}
+
+	@Test public void testRuntimeLexerInterpreterFingerprint() throws Exception {
+		LexerGrammar lg = new LexerGrammar(
+			"lexer grammar L;\n" +
+			"A : 'a' ;\n");
+		String serializedATN = new String(ATNSerializer.getSerializedAsChars(lg, createATN(lg, true)));
+		LexerInterpreter lexer =
+			new LexerInterpreter("L.g4", serializedATN, lg.getTokenDisplayNames(),
+								 lg.rules.keySet().toArray(new String[0]),
+								 lg.modes.keySet().toArray(new String[0]),
+								 null, new ANTLRInputStream("a"));
+		assertEquals(serializedATN, lexer.getSerializedATN());
+		assertEquals(40, lexer.getGrammarFingerprint().length());
+	}
+
 	protected void checkLexerMatches(LexerGrammar lg, String inputString, String expecting) {
 		ATN atn = createATN(lg, true);
 		CharStream input = new ANTLRInputStream(inputString);
@@ -33,13 +33,19 @@ package org.antlr.v4.test;
 import org.antlr.v4.runtime.ANTLRInputStream;
 import org.antlr.v4.runtime.BufferedTokenStream;
 import org.antlr.v4.runtime.CharStream;
+import org.antlr.v4.runtime.CommonToken;
+import org.antlr.v4.runtime.ListTokenSource;
 import org.antlr.v4.runtime.Token;
 import org.antlr.v4.runtime.TokenSource;
 import org.antlr.v4.runtime.TokenStream;
+import org.antlr.v4.runtime.misc.Interval;
 import org.antlr.v4.tool.LexerGrammar;
 import org.antlr.v4.tool.interp.LexerInterpreter;
 import org.junit.Test;
 
+import java.util.ArrayList;
+import java.util.List;
+
 import static org.junit.Assert.*;
 
 public class TestBufferedTokenStream extends BaseTest {
@@ -182,4 +188,17 @@ public class TestBufferedTokenStream extends BaseTest {
         assertEquals("=", t.getText());
     }
 
+	@Test public void testListTokenSource() throws Exception {
+		List<Token> list = new ArrayList<Token>();
+		list.add(new CommonToken(1, "x"));
+		list.add(new CommonToken(2, "="));
+		TokenStream tokens = createTokenStream(new ListTokenSource(list));
+
+		assertEquals(1, tokens.LA(1));
+		assertEquals(2, tokens.LA(2));
+		assertEquals(Token.EOF, tokens.LA(3));
+		assertEquals("x=", tokens.getText(Interval.of(0, 2)));
+		assertEquals("List", tokens.getSourceName());
+	}
+
 }
@@ -34,13 +34,19 @@ import org.antlr.v4.runtime.CharStream;
 import org.antlr.v4.runtime.CommonToken;
 import org.antlr.v4.runtime.CommonTokenStream;
 import org.antlr.v4.runtime.Lexer;
+import org.antlr.v4.runtime.ListTokenSource;
+import org.antlr.v4.runtime.ParserRuleContext;
 import org.antlr.v4.runtime.Token;
 import org.antlr.v4.runtime.TokenFactory;
 import org.antlr.v4.runtime.TokenSource;
 import org.antlr.v4.runtime.TokenStream;
 import org.antlr.v4.runtime.WritableToken;
+import org.antlr.v4.runtime.atn.ATN;
 import org.junit.Test;
 
+import java.util.ArrayList;
+import java.util.List;
+
 import static org.junit.Assert.*;
 
 public class TestCommonTokenStream extends TestBufferedTokenStream {
@@ -305,4 +311,114 @@ public class TestCommonTokenStream extends TestBufferedTokenStream {
 		assertEquals(1, tokens.size());
 		tokens.consume();
 	}
+
+	@Test
+	public void testChannelNamesFromLexer() throws Exception {
+		Lexer lexer = new Lexer() {
+			@Override
+			public String[] getRuleNames() {
+				return null;
+			}
+
+			@Override
+			public String getGrammarFileName() {
+				return "T.g4";
+			}
+
+			@Override
+			public ATN getATN() {
+				return null;
+			}
+
+			@Override
+			public String[] getChannelNames() {
+				return new String[] {"DEFAULT_TOKEN_CHANNEL", "HIDDEN", "COMMENTS"};
+			}
+		};
+
+		CommonToken token = new CommonToken(1, "/* x */");
+		assertEquals("[@-1,0:0='/* x */',<1>,0:-1]", token.toString(lexer));
+
+		token.setChannel(Lexer.HIDDEN);
+		assertEquals("[@-1,0:0='/* x */',<1>,channel=HIDDEN,0:-1]", token.toString(lexer));
+
+		token.setChannel(Lexer.MIN_USER_CHANNEL_VALUE);
+		assertEquals("[@-1,0:0='/* x */',<1>,channel=COMMENTS,0:-1]", token.toString(lexer));
+		assertEquals("[@-1,0:0='/* x */',<1>,channel=2,0:-1]", token.toString());
+
+		// channels the lexer doesn't name are shown by number
+		token.setChannel(Lexer.MIN_USER_CHANNEL_VALUE + 1);
+		assertEquals("[@-1,0:0='/* x */',<1>,channel=3,0:-1]", token.toString(lexer));
+	}
+
+	@Test public void testFullTokenRange() throws Exception {
+		List<Token> tokenList = new ArrayList<Token>();
+		tokenList.add(new CommonToken(1,"x"));
+		tokenList.add(new CommonToken(1," ") {{channel = Lexer.HIDDEN;}});
+		tokenList.add(new CommonToken(1,"/* c */") {{channel = Lexer.HIDDEN;}});
+		tokenList.add(new CommonToken(1,"="));
+		tokenList.add(new CommonToken(1," ") {{channel = Lexer.HIDDEN;}});
+		tokenList.add(new CommonToken(1,"34"));
+		CommonTokenStream tokens = new CommonTokenStream(new ListTokenSource(tokenList));
+		tokens.fill();
+
+		ParserRuleContext ctx = new ParserRuleContext();
+		ctx.start = tokens.get(0);
+		ctx.stop = tokens.get(3);
+		StringBuilder buf = new StringBuilder();
+		for (Token t : ctx.getFullTokenRange(tokens)) buf.append(t.getText());
+		assertEquals("x /* c */=", buf.toString());
+
+		// a rule that matched nothing stops before it starts
+		ctx.start = tokens.get(5);
+		ctx.stop = tokens.get(3);
+		assertTrue(ctx.getFullTokenRange(tokens).isEmpty());
+	}
+
+	@Test public void testStreamsSharingFilledBuffer() throws Exception {
+		List<Token> tokenList = new ArrayList<Token>();
+		tokenList.add(new CommonToken(1,"x"));
+		tokenList.add(new CommonToken(1," ") {{channel = Lexer.HIDDEN;}});
+		tokenList.add(new CommonToken(1,"="));
+		tokenList.add(new CommonToken(1,"34"));
+		CommonTokenStream tokens = new CommonTokenStream(new ListTokenSource(tokenList));
+		tokens.fill();
+
+		CommonTokenStream a = new CommonTokenStream(tokens);
+		CommonTokenStream b = new CommonTokenStream(tokens);
+		b.seek(2);
+		assertEquals("x", a.LT(1).getText());
+		assertEquals("=", a.LT(2).getText()); // hidden ' ' skipped
+		assertEquals("=", b.LT(1).getText());
+		b.consume();
+		assertEquals("x", a.LT(1).getText());
+		assertEquals("34", b.LT(1).getText());
+		assertSame(tokens.get(3), b.LT(1));
+		assertEquals(tokens.size(), b.size());
+	}
+
+	@Test(expected=IllegalArgumentException.class)
+	public void testSharingUnfilledBuffer() throws Exception {
+		List<Token> tokenList = new ArrayList<Token>();
+		tokenList.add(new CommonToken(1,"x"));
+		CommonTokenStream tokens = new CommonTokenStream(new ListTokenSource(tokenList));
+		new CommonTokenStream(tokens);
+	}
+
+	@Test public void testFetchSize() throws Exception {
+		List<Token> tokenList = new ArrayList<Token>();
+		for (int i = 0; i < 10; i++) {
+			tokenList.add(new CommonToken(1, "x"));
+		}
+		CommonTokenStream tokens = new CommonTokenStream(new ListTokenSource(tokenList));
+		tokens.setFetchSize(4);
+		assertEquals(1, tokens.LA(1));
+		assertEquals(4, tokens.size());
+		tokens.LT(5);
+		assertEquals(8, tokens.size());
+		tokens.LT(10);
+		// stops at EOF
+		assertEquals(11, tokens.size());
+		assertEquals(Token.EOF, tokens.get(10).getType());
+	}
 }
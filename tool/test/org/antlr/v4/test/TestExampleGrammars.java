@@ -0,0 +1,181 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.test;
+
+import org.junit.Test;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertNull;
+import static org.junit.Assert.assertTrue;
+
+/** Complete example grammars run end to end through lexer, parser,
+ *  listeners, visitors, error recovery and the token rewriter.
+ */
+public class TestExampleGrammars extends BaseTest {
+	public static final String JSON =
+		"grammar JSON;\n" +
+		"@header {import org.antlr.v4.runtime.tree.*;}\n" +
+		"@members {\n" +
+		"public static class KeyLister extends JSONBaseListener {\n" +
+		"  public void exitPair(PairContext ctx) {\n" +
+		"    System.out.println(ctx.STRING().getText());\n" +
+		"  }\n" +
+		"}\n" +
+		"}\n" +
+		"json\n" +
+		"@after {\n" +
+		"  System.out.println($ctx.toStringTree(this));\n" +
+		"  new ParseTreeWalker().walk(new KeyLister(), $ctx);\n" +
+		"}\n" +
+		"  : value EOF ;\n" +
+		"object\n" +
+		"  : '{' pair (',' pair)* '}'\n" +
+		"  | '{' '}'\n" +
+		"  ;\n" +
+		"pair : STRING ':' value ;\n" +
+		"array\n" +
+		"  : '[' value (',' value)* ']'\n" +
+		"  | '[' ']'\n" +
+		"  ;\n" +
+		"value\n" +
+		"  : STRING\n" +
+		"  | NUMBER\n" +
+		"  | object\n" +
+		"  | array\n" +
+		"  | 'true'\n" +
+		"  | 'false'\n" +
+		"  | 'null'\n" +
+		"  ;\n" +
+		"STRING : '\"' (ESC | ~[\"\\\\])* '\"' ;\n" +
+		"fragment ESC : '\\\\' ([\"\\\\/bfnrt] | UNICODE) ;\n" +
+		"fragment UNICODE : 'u' HEX HEX HEX HEX ;\n" +
+		"fragment HEX : [0-9a-fA-F] ;\n" +
+		"NUMBER : '-'? INT ('.' [0-9]+)? EXP? ;\n" +
+		"fragment INT : '0' | [1-9] [0-9]* ;\n" +
+		"fragment EXP : [Ee] ('+'|'-')? INT ;\n" +
+		"WS : [ \\t\\n\\r]+ -> skip ;\n";
+
+	public static final String EXPR =
+		"grammar Expr;\n" +
+		"@header {\n" +
+		"import org.antlr.v4.runtime.tree.*;\n" +
+		"import java.util.*;\n" +
+		"}\n" +
+		"@members {\n" +
+		"public static class Eval extends ExprBaseVisitor<Integer> {\n" +
+		"  Map<String, Integer> memory = new HashMap<String, Integer>();\n" +
+		"  public Integer visitAssign(AssignContext ctx) {\n" +
+		"    memory.put(ctx.ID().getText(), visit(ctx.e()));\n" +
+		"    return null;\n" +
+		"  }\n" +
+		"  public Integer visitPrint(PrintContext ctx) {\n" +
+		"    System.out.println(visit(ctx.e()));\n" +
+		"    return null;\n" +
+		"  }\n" +
+		"  public Integer visitMulDiv(MulDivContext ctx) {\n" +
+		"    int a = visit(ctx.e(0));\n" +
+		"    int b = visit(ctx.e(1));\n" +
+		"    return ctx.op.getText().equals(\"*\") ? a*b : a/b;\n" +
+		"  }\n" +
+		"  public Integer visitAddSub(AddSubContext ctx) {\n" +
+		"    int a = visit(ctx.e(0));\n" +
+		"    int b = visit(ctx.e(1));\n" +
+		"    return ctx.op.getText().equals(\"+\") ? a+b : a-b;\n" +
+		"  }\n" +
+		"  public Integer visitInt(IntContext ctx) { return Integer.valueOf(ctx.INT().getText()); }\n" +
+		"  public Integer visitVar(VarContext ctx) { return memory.get(ctx.ID().getText()); }\n" +
+		"  public Integer visitParens(ParensContext ctx) { return visit(ctx.e()); }\n" +
+		"}\n" +
+		"public static class Parenthesizer extends ExprBaseListener {\n" +
+		"  TokenStreamRewriter rewriter;\n" +
+		"  Parenthesizer(TokenStreamRewriter rewriter) { this.rewriter = rewriter; }\n" +
+		"  public void exitMulDiv(MulDivContext ctx) {\n" +
+		"    rewriter.insertBefore(ctx.start, \"(\");\n" +
+		"    rewriter.insertAfter(ctx.stop, \")\");\n" +
+		"  }\n" +
+		"}\n" +
+		"}\n" +
+		"prog\n" +
+		"@after {\n" +
+		"  new Eval().visit($ctx);\n" +
+		"  TokenStreamRewriter rewriter = new TokenStreamRewriter(_input);\n" +
+		"  new ParseTreeWalker().walk(new Parenthesizer(rewriter), $ctx);\n" +
+		"  System.out.println(rewriter.getText());\n" +
+		"}\n" +
+		"  : stat+ EOF ;\n" +
+		"stat\n" +
+		"  : ID '=' e ';'    # Assign\n" +
+		"  | e ';'           # Print\n" +
+		"  ;\n" +
+		"e : e op=('*'|'/') e  # MulDiv\n" +
+		"  | e op=('+'|'-') e  # AddSub\n" +
+		"  | INT               # Int\n" +
+		"  | ID                # Var\n" +
+		"  | '(' e ')'         # Parens\n" +
+		"  ;\n" +
+		"ID : [a-z]+ ;\n" +
+		"INT : [0-9]+ ;\n" +
+		"WS : [ \\t\\r\\n]+ -> skip ;\n";
+
+	@Test public void testJSON() throws Exception {
+		String input = "{\"a\": [1, -2.5e3, true], \"b\\\"x\": {\"c\": null}, \"d\": \"x\\/y\"}";
+		String found = execParser("JSON.g4", JSON, "JSONParser", "JSONLexer", "json", input, false);
+		String expecting =
+			"(json (value (object { " +
+			"(pair \"a\" : (value (array [ (value 1) , (value -2.5e3) , (value true) ]))) , " +
+			"(pair \"b\\\"x\" : (value (object { (pair \"c\" : (value null)) }))) , " +
+			"(pair \"d\" : (value \"x\\/y\")) })) <EOF>)\n" +
+			"\"a\"\n" +
+			"\"c\"\n" +
+			"\"b\\\"x\"\n" +
+			"\"d\"\n";
+		assertEquals(expecting, found);
+		assertNull(stderrDuringParse);
+	}
+
+	@Test public void testJSONErrorRecovery() throws Exception {
+		String found = execParser("JSON.g4", JSON, "JSONParser", "JSONLexer", "json", "[1 2]", false);
+		assertEquals("(json (value (array [ (value 1) 2 ])) <EOF>)\n", found);
+		assertTrue(stderrDuringParse.startsWith("line 1:3 extraneous input '2'"));
+	}
+
+	@Test public void testExprVisitorAndRewriter() throws Exception {
+		String input =
+			"a = 3 + 4 * 2;\n" +
+			"b = (a - 1) / 2;\n" +
+			"a * b;\n";
+		String found = execParser("Expr.g4", EXPR, "ExprParser", "ExprLexer", "prog", input, false);
+		String expecting =
+			"55\n" +
+			"a=3+(4*2);b=((a-1)/2);(a*b);\n";
+		assertEquals(expecting, found);
+		assertNull(stderrDuringParse);
+	}
+}
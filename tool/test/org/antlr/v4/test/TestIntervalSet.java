@@ -31,6 +31,7 @@
 package org.antlr.v4.test;
 
 import org.antlr.v4.runtime.Lexer;
+import org.antlr.v4.runtime.Token;
 import org.antlr.v4.runtime.misc.IntervalSet;
 import org.junit.Test;
 
@@ -419,5 +420,69 @@ public class TestIntervalSet extends BaseTest {
         assertEquals(expecting, result);
     }
 
+    @Test public void testContainsManyIntervals() throws Exception {
+        IntervalSet s = new IntervalSet();
+        for (int i = 0; i < 100; i++) {
+            s.add(i * 10, i * 10 + 4);
+        }
+        assertTrue(s.contains(0));
+        assertTrue(s.contains(4));
+        assertFalse(s.contains(5));
+        assertFalse(s.contains(9));
+        assertTrue(s.contains(500));
+        assertTrue(s.contains(994));
+        assertFalse(s.contains(995));
+        assertFalse(s.contains(-1));
+        assertFalse(s.contains(Lexer.MAX_CHAR_VALUE));
+    }
+
+    @Test public void testContainsEmptySet() throws Exception {
+        IntervalSet s = new IntervalSet();
+        assertFalse(s.contains(0));
+        assertFalse(s.contains(Lexer.MIN_CHAR_VALUE));
+    }
+
+    @Test public void testSubtractDropsElementsOutsideCharRange() throws Exception {
+        IntervalSet s = IntervalSet.of(Token.EPSILON, Token.EOF);
+        s.add(5);
+        String expecting = "5";
+        String result = s.subtract(IntervalSet.of(Token.EPSILON)).toString();
+        assertEquals(expecting, result);
+    }
+
+    @Test public void testXorAndSubsetKeepEOF() throws Exception {
+        IntervalSet s = IntervalSet.of(Token.EOF);
+        s.add(5);
+        assertEquals("{<EOF>, 6}", s.xor(IntervalSet.of(5,6)).toString());
+        assertFalse(s.isSubsetOf(IntervalSet.of(5)));
+        assertTrue(IntervalSet.of(5).isSubsetOf(s));
+    }
+
+    @Test public void testXor() throws Exception {
+        IntervalSet s = IntervalSet.of(1,10);
+        IntervalSet s2 = IntervalSet.of(5,15);
+        assertEquals("{1..4, 11..15}", s.xor(s2).toString());
+        assertEquals("{}", s.xor(s).toString());
+    }
+
+    @Test public void testSubsetAndSuperset() throws Exception {
+        IntervalSet s = IntervalSet.of(1,10);
+        s.add(20,30);
+        IntervalSet s2 = IntervalSet.of(2,3);
+        s2.add(25);
+        assertTrue(s2.isSubsetOf(s));
+        assertTrue(s.isSupersetOf(s2));
+        assertFalse(s.isSubsetOf(s2));
+        assertTrue(s.isSubsetOf(s));
+        assertTrue(new IntervalSet().isSubsetOf(s));
+        assertFalse(IntervalSet.of(10,20).isSubsetOf(s));
+    }
+
+    @Test public void testOverlaps() throws Exception {
+        IntervalSet s = IntervalSet.of(1,10);
+        assertTrue(s.overlaps(IntervalSet.of(10,20)));
+        assertFalse(s.overlaps(IntervalSet.of(11,20)));
+        assertFalse(s.overlaps(new IntervalSet()));
+    }
 
 }
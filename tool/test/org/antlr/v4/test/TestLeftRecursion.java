@@ -64,6 +64,19 @@ public class TestLeftRecursion extends BaseTest {
 		assertEquals(expecting, found);
 	}
 
+	@Test public void testGetPrecedence() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s : {System.out.println(getPrecedence());} e ;\n" +
+			"e : e '*' e\n" +
+			"  | INT {System.out.println(getPrecedence());}\n" +
+			"  ;\n" +
+			"INT : '0'..'9'+ ;\n";
+		String found = execParser("T.g4", grammar, "TParser", "TLexer",
+								  "s", "1", debug);
+		assertEquals("-1\n0\n", found);
+	}
+
 	@Test public void testSemPred() throws Exception {
 		String grammar =
 			"grammar T;\n" +
@@ -374,6 +387,30 @@ public class TestLeftRecursion extends BaseTest {
 					 stderrDuringParse);
 	}
 
+	@Test public void testPrecedenceStartStatesReusedWithinParse() throws Exception {
+		// each statement predicts the operator loop at several precedence
+		// levels; later statements reuse the cached start state per level
+		String grammar =
+			"grammar T;\n" +
+			"s @after {System.out.println($ctx.toStringTree(this));} : (e ';')+ EOF ;\n" +
+			"e : '-' e\n" +
+			"  | e '*' e\n" +
+			"  | e '+' e\n" +
+			"  | ID\n" +
+			"  ;\n" +
+			"ID : 'a'..'z'+ ;\n" +
+			"WS : (' '|'\\n') -> skip ;\n";
+		String[] tests = {
+			"a+b*c; a*b+c;",
+				"(s (e (e a) + (e (e b) * (e c))) ; (e (e (e a) * (e b)) + (e c)) ; <EOF>)",
+			"a*b+c; a+b*c; a+b+c;",
+				"(s (e (e (e a) * (e b)) + (e c)) ; (e (e a) + (e (e b) * (e c))) ; (e (e (e a) + (e b)) + (e c)) ; <EOF>)",
+			"-a*b; a*-b+c;",
+				"(s (e (e - (e a)) * (e b)) ; (e (e (e a) * (e - (e b))) + (e c)) ; <EOF>)",
+		};
+		runTests(grammar, tests, "s");
+	}
+
 	@Test public void testCheckForNonLeftRecursiveRule() throws Exception {
 		String grammar =
 			"grammar T;\n" +
@@ -70,6 +70,75 @@ public class TestLexerErrors extends BaseTest {
 		assertEquals("line 1:0 token recognition error at: '[\"foo]'\n", stderrDuringParse);
 	}
 
+	@Test public void testMaxTokenLength() throws Exception {
+		String grammar =
+			"lexer grammar L;\n" +
+			"@members {\n" +
+			"public Token nextToken() {\n" +
+			"  getInterpreter().setMaxTokenLength(5);\n" +
+			"  return super.nextToken();\n" +
+			"}\n" +
+			"}\n" +
+			"STRING : '\"' .*? '\"' ;\n" +
+			"ID : [a-z]+ ;\n" +
+			"WS : ' ' -> skip ;\n";
+		String tokens = execLexer("L.g4", grammar, "L", "\"abc\" \"abcdefgh");
+		String expectingTokens =
+			"[@0,0:4='\"abc\"',<1>,1:0]\n" +
+			"[@1,11:14='efgh',<2>,1:11]\n" +
+			"[@2,15:14='<EOF>',<-1>,1:15]\n";
+		assertEquals(expectingTokens, tokens);
+		assertEquals("line 1:6 token recognition error at: '\"abcd'\n", stderrDuringParse);
+	}
+
+	@Test public void testMaxScanAheadReturnsLastToken() throws Exception {
+		String grammar =
+			"lexer grammar L;\n" +
+			"@members {\n" +
+			"public Token nextToken() {\n" +
+			"  getInterpreter().setMaxScanAhead(3);\n" +
+			"  return super.nextToken();\n" +
+			"}\n" +
+			"}\n" +
+			"A : 'a' ;\n" +
+			"LONG : 'a' 'b'* 'c' ;\n" +
+			"B : 'b' ;\n" +
+			"WS : ' ' -> skip ;\n";
+		String tokens = execLexer("L.g4", grammar, "L", "abbc abbbbbbb");
+		String expectingTokens =
+			"[@0,0:3='abbc',<2>,1:0]\n" +
+			"[@1,5:5='a',<1>,1:5]\n" +
+			"[@2,6:6='b',<3>,1:6]\n" +
+			"[@3,7:7='b',<3>,1:7]\n" +
+			"[@4,8:8='b',<3>,1:8]\n" +
+			"[@5,9:9='b',<3>,1:9]\n" +
+			"[@6,10:10='b',<3>,1:10]\n" +
+			"[@7,11:11='b',<3>,1:11]\n" +
+			"[@8,12:12='b',<3>,1:12]\n" +
+			"[@9,13:12='<EOF>',<-1>,1:13]\n";
+		assertEquals(expectingTokens, tokens);
+		assertNull(stderrDuringParse);
+	}
+
+	@Test public void testMaxScanAheadWithNoToken() throws Exception {
+		String grammar =
+			"lexer grammar L;\n" +
+			"@members {\n" +
+			"public Token nextToken() {\n" +
+			"  getInterpreter().setMaxScanAhead(3);\n" +
+			"  return super.nextToken();\n" +
+			"}\n" +
+			"}\n" +
+			"STRING : '\"' .*? '\"' ;\n" +
+			"ID : [a-z]+ ;\n";
+		String tokens = execLexer("L.g4", grammar, "L", "\"abcdefgh");
+		String expectingTokens =
+			"[@0,3:8='cdefgh',<2>,1:3]\n" +
+			"[@1,9:8='<EOF>',<-1>,1:9]\n";
+		assertEquals(expectingTokens, tokens);
+		assertEquals("line 1:0 token recognition error at: '\"ab'\n", stderrDuringParse);
+	}
+
 	@Test public void testEnforcedGreedyNestedBrances() {
 		String grammar =
 			"lexer grammar R;\n"
@@ -183,6 +252,34 @@ public class TestLexerErrors extends BaseTest {
 		assertEquals(expectingError, error);
 	}
 
+	@Test public void testPopModeOnEmptyStack() throws Exception {
+		String grammar =
+			"lexer grammar L;\n" +
+			"A : 'a' -> popMode ;\n" +
+			"B : 'b' ;\n";
+		String tokens = execLexer("L.g4", grammar, "L", "ab");
+		String expectingTokens =
+			"[@0,0:0='a',<1>,1:0]\n" +
+			"[@1,1:1='b',<2>,1:1]\n" +
+			"[@2,2:1='<EOF>',<-1>,1:2]\n";
+		assertEquals(expectingTokens, tokens);
+		assertEquals("line 1:0 popMode with empty mode stack\n", stderrDuringParse);
+	}
+
+	@Test public void testSwitchToUndefinedMode() throws Exception {
+		String grammar =
+			"lexer grammar L;\n" +
+			"A : 'a' {mode(42);} ;\n" +
+			"B : 'b' ;\n";
+		String tokens = execLexer("L.g4", grammar, "L", "ab");
+		String expectingTokens =
+			"[@0,0:0='a',<1>,1:0]\n" +
+			"[@1,1:1='b',<2>,1:1]\n" +
+			"[@2,2:1='<EOF>',<-1>,1:2]\n";
+		assertEquals(expectingTokens, tokens);
+		assertEquals("line 1:1 no such lexer mode: 42\n", stderrDuringParse);
+	}
+
 	// TEST RECOVERY
 
 	/**
@@ -349,6 +349,89 @@ public class TestLexerExec extends BaseTest {
 		assertEquals(expecting, found);
 	}
 
+	/** The tool rejects rules that match the empty string, so these
+	 *  install a simulator that returns skip/more on '!' without
+	 *  consuming anything, as a broken hand-written lexer might.
+	 */
+	private static String stuckLexerMembers(String command) {
+		return
+			"@members {\n" +
+			"LexerATNSimulator stuck;\n" +
+			"@Override\n" +
+			"public LexerATNSimulator getInterpreter() {\n" +
+			"	if ( stuck==null ) {\n" +
+			"		stuck = new LexerATNSimulator(this,_ATN,_decisionToDFA,_sharedContextCache) {\n" +
+			"			@Override\n" +
+			"			public int match(CharStream input, int mode) {\n" +
+			"				if ( input.LA(1)=='!' ) return Lexer."+command+";\n" +
+			"				return super.match(input, mode);\n" +
+			"			}\n" +
+			"		};\n" +
+			"	}\n" +
+			"	return stuck;\n" +
+			"}\n" +
+			"}\n";
+	}
+
+	@Test public void testSkipWithNoProgress() throws Exception {
+		String grammar =
+			"lexer grammar L;\n"+
+			stuckLexerMembers("SKIP") +
+			"A : 'a' ;\n";
+		String found = execLexer("L.g4", grammar, "L", "a!a");
+		String expecting =
+			"[@0,0:0='a',<1>,1:0]\n" +
+			"[@1,2:2='a',<1>,1:2]\n" +
+			"[@2,3:2='<EOF>',<-1>,1:3]\n";
+		assertEquals(expecting, found);
+		assertEquals("line 1:1 lexer made no progress at: '!'\n", stderrDuringParse);
+	}
+
+	@Test public void testMoreWithNoProgress() throws Exception {
+		String grammar =
+			"lexer grammar L;\n"+
+			stuckLexerMembers("MORE") +
+			"A : 'a' ;\n";
+		String found = execLexer("L.g4", grammar, "L", "a!a");
+		String expecting =
+			"[@0,0:0='a',<1>,1:0]\n" +
+			"[@1,1:2='!a',<1>,1:1]\n" +
+			"[@2,3:2='<EOF>',<-1>,1:3]\n";
+		assertEquals(expecting, found);
+		assertEquals("line 1:1 lexer made no progress at: '!'\n", stderrDuringParse);
+	}
+
+	/** An undefined mode is only noticed when the lexer next tries to
+	 *  match in it; with more() that is still within the same token.
+	 */
+	@Test public void testMoreIntoUndefinedMode() throws Exception {
+		String grammar =
+			"lexer grammar L;\n"+
+			"A : 'a' {mode(42); more();} ;\n"+
+			"B : 'b' ;\n";
+		String found = execLexer("L.g4", grammar, "L", "ab");
+		String expecting =
+			"[@0,0:1='ab',<2>,1:0]\n" +
+			"[@1,2:1='<EOF>',<-1>,1:2]\n";
+		assertEquals(expecting, found);
+		assertEquals("line 1:0 no such lexer mode: 42\n", stderrDuringParse);
+	}
+
+	/** Nothing is matched after the last token, so an undefined mode
+	 *  selected there is never used and never reported.
+	 */
+	@Test public void testUndefinedModeAtEOF() throws Exception {
+		String grammar =
+			"lexer grammar L;\n"+
+			"A : 'a' {mode(42);} ;\n";
+		String found = execLexer("L.g4", grammar, "L", "a");
+		String expecting =
+			"[@0,0:0='a',<1>,1:0]\n" +
+			"[@1,1:0='<EOF>',<-1>,1:1]\n";
+		assertEquals(expecting, found);
+		assertNull(stderrDuringParse);
+	}
+
 	@Test public void testTypeCommand() throws Exception {
 		String grammar =
 			"lexer grammar L;\n"+
@@ -663,6 +746,80 @@ public class TestLexerExec extends BaseTest {
 		assertEquals(expecting, found);
 	}
 
+	@Test public void testTrace() throws Exception {
+		String grammar =
+			"lexer grammar L;\n"+
+			"@members { { setTrace(true); } }\n"+
+			"ID : [a-z]+ ;\n"+
+			"WS : ' ' {skip();} ;\n";
+		String found = execLexer("L.g4", grammar, "L", "ab c");
+		String expecting =
+			"match mode=DEFAULT_MODE start=0 rule=ID -> ID channel=DEFAULT_TOKEN_CHANNEL\n" +
+			"match mode=DEFAULT_MODE start=2 rule=WS action=0 -> skip\n" +
+			"match mode=DEFAULT_MODE start=3 rule=ID -> ID channel=DEFAULT_TOKEN_CHANNEL\n" +
+			"[@0,0:1='ab',<1>,1:0]\n" +
+			"[@1,3:3='c',<1>,1:3]\n" +
+			"[@2,4:3='<EOF>',<-1>,1:4]\n";
+		assertEquals(expecting, found);
+		assertNull(stderrDuringParse);
+	}
+
+	@Test public void testTraceErrorAfterMatchedPrefix() throws Exception {
+		String grammar =
+			"lexer grammar L;\n"+
+			"@members {\n" +
+			"{ setTrace(true); }\n" +
+			"public Token nextToken() {\n" +
+			"  getInterpreter().setMaxTokenLength(3);\n" +
+			"  return super.nextToken();\n" +
+			"}\n" +
+			"}\n" +
+			"ID : [a-z]+ ;\n";
+		String found = execLexer("L.g4", grammar, "L", "abcdef");
+		String expecting =
+			"match mode=DEFAULT_MODE start=0 -> no viable token\n" +
+			"match mode=DEFAULT_MODE start=3 rule=ID -> ID channel=DEFAULT_TOKEN_CHANNEL\n" +
+			"[@0,3:5='def',<1>,1:3]\n" +
+			"[@1,6:5='<EOF>',<-1>,1:6]\n";
+		assertEquals(expecting, found);
+		assertEquals("line 1:0 token recognition error at: 'abc'\n", stderrDuringParse);
+	}
+
+	/** The serialized ATN is too big for one Java string constant; the
+	 *  generated lexer must split it or it won't compile.
+	 */
+	@Test public void testLargeLexer() throws Exception {
+		StringBuilder grammar = new StringBuilder();
+		grammar.append("lexer grammar L;\n");
+		for (int i = 0; i < 1500; i++) {
+			grammar.append("KW").append(i).append(" : 'kw").append(i).append("' ;\n");
+		}
+		grammar.append("WS : [ ]+ -> skip ;");
+		String found = execLexer("L.g4", grammar.toString(), "L", "kw1 kw1499");
+		String expecting =
+			"[@0,0:2='kw1',<2>,1:0]\n" +
+			"[@1,4:9='kw1499',<1500>,1:4]\n" +
+			"[@2,10:9='<EOF>',<-1>,1:10]\n";
+		assertEquals(expecting, found);
+	}
+
+	@Test public void testCaseInsensitive() throws Exception {
+		String grammar =
+			"lexer grammar L;\n"+
+			"options {caseInsensitive=true;}\n"+
+			"SELECT : 'select' ;\n"+
+			"ID : [a-z]+ ;\n"+
+			"HEX : '0x' ('0'..'9'|'a'..'f')+ ;\n"+
+			"WS : [ ]+ -> skip ;";
+		String found = execLexer("L.g4", grammar, "L", "SeLeCt Foo 0XaF");
+		String expecting =
+			"[@0,0:5='SeLeCt',<1>,1:0]\n" +
+			"[@1,7:9='Foo',<2>,1:7]\n" +
+			"[@2,11:14='0XaF',<3>,1:11]\n" +
+			"[@3,15:14='<EOF>',<-1>,1:15]\n";
+		assertEquals(expecting, found);
+	}
+
 	@Test
 	public void testPositionAdjustingLexer() throws Exception {
 		String grammar = load("PositionAdjustingLexer.g4", null);
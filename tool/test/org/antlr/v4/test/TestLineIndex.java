@@ -0,0 +1,70 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.test;
+
+import org.antlr.v4.runtime.ANTLRInputStream;
+import org.antlr.v4.runtime.misc.LineIndex;
+import org.junit.Test;
+
+import static org.junit.Assert.assertEquals;
+
+public class TestLineIndex extends BaseTest {
+	@Test public void testOffsetToLineAndColumn() {
+		LineIndex index = new LineIndex("ab\ncd\n\nef");
+		assertEquals(4, index.getNumberOfLines());
+		assertEquals(1, index.getLine(0));
+		assertEquals(1, index.getLine(2)); // the '\n' ends line 1
+		assertEquals(2, index.getCharPositionInLine(2));
+		assertEquals(2, index.getLine(3));
+		assertEquals(0, index.getCharPositionInLine(3));
+		assertEquals(3, index.getLine(6));
+		assertEquals(4, index.getLine(8));
+		assertEquals(1, index.getCharPositionInLine(8));
+		assertEquals(4, index.getLine(9)); // EOF
+		assertEquals(2, index.getCharPositionInLine(9));
+	}
+
+	@Test public void testLineAndColumnToOffset() {
+		LineIndex index = new LineIndex(new ANTLRInputStream("ab\ncd\n\nef"));
+		assertEquals(0, index.getOffset(1, 0));
+		assertEquals(2, index.getOffset(1, 2));
+		assertEquals(4, index.getOffset(2, 1));
+		assertEquals(6, index.getOffset(3, 0));
+		assertEquals(9, index.getOffset(4, 2));
+		for (int i=0; i<=index.size(); i++) {
+			assertEquals(i, index.getOffset(index.getLine(i), index.getCharPositionInLine(i)));
+		}
+	}
+
+	@Test(expected=IllegalArgumentException.class)
+	public void testColumnPastEndOfLine() {
+		new LineIndex("ab\ncd").getOffset(1, 3);
+	}
+}
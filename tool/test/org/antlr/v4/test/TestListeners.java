@@ -223,4 +223,55 @@ public class TestListeners extends BaseTest {
 			"1 [13 6]\n";
 		assertEquals(expecting, result);
 	}
+
+	/** The listener is added in top's @init, after top was entered, so
+	 *  top itself is not timed and its exit must be ignored.
+	 */
+	@Test public void testRuleTiming() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"@members {RuleTimingListener timing;}\n" +
+			"top\n" +
+			"@init {timing = new RuleTimingListener(this); addParseListener(timing);}\n" +
+			"@after {" +
+			"  System.out.println(\"top=\"+timing.getInvocations(RULE_top)+\" s=\"+timing.getInvocations(RULE_s)+\" e=\"+timing.getInvocations(RULE_e));" +
+			"  System.out.println(timing.getSelfTime(RULE_e) <= timing.getCumulativeTime(RULE_e));" +
+			"}\n" +
+			"  : s ;\n" +
+			"s : e ';' e ;\n" +
+			"e : INT | '(' e ')' ;\n" +
+			"INT : [0-9]+ ;\n" +
+			"WS : [ \\t\\n]+ -> skip ;\n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "top", "((1)) ; 2", false);
+		String expecting = "top=0 s=1 e=4\n" +
+						   "true\n";
+		assertEquals(expecting, result);
+		assertNull(stderrDuringParse);
+	}
+
+	@Test public void testRuleDocCommentsInGeneratedCode() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"/** A pair of ints */\n" +
+			"s : INT INT ;\n" +
+			"INT : [0-9]+ ;\n";
+		assertTrue(antlr("T.g4", "T.g4", grammar, false, "-visitor"));
+		String doc = "/** A pair of ints */";
+		String listener = readGeneratedFile("TListener.java");
+		assertTrue(listener.contains(doc+"\n\tvoid enterS("));
+		String visitor = readGeneratedFile("TVisitor.java");
+		assertTrue(visitor.contains(doc+"\n\tT visitS("));
+		String parser = readGeneratedFile("TParser.java");
+		assertTrue(parser.contains(doc+"\n\tpublic static class SContext "));
+	}
+
+	protected String readGeneratedFile(String fileName) throws Exception {
+		java.util.Scanner scanner = new java.util.Scanner(new java.io.File(tmpdir, fileName));
+		try {
+			return scanner.useDelimiter("\\A").next();
+		}
+		finally {
+			scanner.close();
+		}
+	}
 }
@@ -31,6 +31,7 @@
 package org.antlr.v4.test;
 
 import org.antlr.v4.automata.ATNSerializer;
+import org.antlr.v4.runtime.DefaultErrorStrategy;
 import org.junit.Test;
 
 import static org.junit.Assert.*;
@@ -385,4 +386,127 @@ public class TestParseErrors extends BaseTest {
 		String result = stderrDuringParse;
 		assertEquals(expecting, result);
 	}
+
+	/** Every r fails at EOF without consuming anything; once the bound on
+	 *  recoveries without progress is exceeded the parse is cancelled.
+	 */
+	@Test public void testNoProgressRecoveryCancelsParse() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s @init {((DefaultErrorStrategy)_errHandler).setMaxRecoveriesWithoutProgress(2);}\n" +
+			"  : r r r r EOF ;\n" +
+			"  catch [ParseCancellationException e] {System.out.println(e.getMessage());}\n" +
+			"r : 'a' ;";
+		String found = execParser("T.g4", grammar, "TParser", "TLexer", "s", "", false);
+		assertEquals("error recovery made no progress in rule r at '<EOF>'\n", found);
+		String expecting =
+			"line 1:0 mismatched input '<EOF>' expecting 'a'\n" +
+			"line 1:0 error recovery made no progress in rule r at '<EOF>'\n";
+		String result = stderrDuringParse;
+		assertEquals(expecting, result);
+	}
+
+	/** Unwinding out of deeply nested rules at EOF recovers at the same
+	 *  index and state in every invocation; that is not a loop and must
+	 *  not cancel the parse.
+	 */
+	@Test public void testDeeplyNestedTruncatedInputRecovers() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s @init {((DefaultErrorStrategy)_errHandler).setMaxRecoveriesWithoutProgress(2);}\n" +
+			"  : e EOF {System.out.println(\"done\");} ;\n" +
+			"e : '(' e ')' | INT ;\n" +
+			"INT : [0-9]+ ;\n";
+		String found = execParser("T.g4", grammar, "TParser", "TLexer", "s", "((((((((((", false);
+		assertEquals("done\n", found);
+		assertEquals("line 1:10 no viable alternative at input '<EOF>'\n", stderrDuringParse);
+	}
+
+	@Test(expected=IllegalArgumentException.class)
+	public void testMaxRecoveriesWithoutProgressMustBePositive() {
+		new DefaultErrorStrategy().setMaxRecoveriesWithoutProgress(0);
+	}
+
+	@Test public void testExplainingErrorListener() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s @init {removeErrorListeners(); addErrorListener(ExplainingErrorListener.INSTANCE);}\n" +
+			"  : a ;\n" +
+			"a : 'x' ID ';' ;\n" +
+			"ID : [a-z]+ ;\n" +
+			"WS : [ \\t\\r\\n]+ -> skip;\n";
+		execParser("T.g4", grammar, "TParser", "TLexer", "s", "x ;", false);
+		String expecting =
+			"line 1:2 missing ID at ';'\n" +
+			"x ;\n" +
+			"  ^\n" +
+			"rule stack: [a, s]\n" +
+			"expected tokens: ID\n" +
+			"recovery: insert ID\n";
+		String result = stderrDuringParse;
+		assertEquals(expecting, result);
+	}
+
+	@Test public void testExplainingErrorListenerDeletionAndResync() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s @init {removeErrorListeners(); addErrorListener(ExplainingErrorListener.INSTANCE);}\n" +
+			"  : a a EOF ;\n" +
+			"a : 'x' ';' | 'y' ';' ;\n" +
+			"WS : [ \\t\\r\\n]+ -> skip;\n";
+		execParser("T.g4", grammar, "TParser", "TLexer", "s", "x x ; ; x ;", false);
+		String expecting =
+			"line 1:2 extraneous input 'x' expecting ';'\n" +
+			"x x ; ; x ;\n" +
+			"  ^\n" +
+			"rule stack: [a, s]\n" +
+			"expected literals: ';'\n" +
+			"recovery: delete 'x'\n" +
+			"line 1:6 no viable alternative at input ';'\n" +
+			"x x ; ; x ;\n" +
+			"      ^\n" +
+			"rule stack: [a, s]\n" +
+			"expected literals: 'x', 'y'\n" +
+			"recovery: resync by consuming until <EOF>\n";
+		String result = stderrDuringParse;
+		assertEquals(expecting, result);
+	}
+
+	/** The second r fails where the first one's recovery left off, so
+	 *  recover() drops 'x' before resynchronizing. The default strategy
+	 *  stays quiet about that repeat; this one reports every error.
+	 */
+	@Test public void testExplainingErrorListenerStuckRecovery() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s @init {\n" +
+			"  removeErrorListeners(); addErrorListener(ExplainingErrorListener.INSTANCE);\n" +
+			"  setErrorHandler(new DefaultErrorStrategy() {\n" +
+			"    @Override\n" +
+			"    public void reportError(Parser recognizer, RecognitionException e) {\n" +
+			"      errorRecoveryMode = false;\n" +
+			"      super.reportError(recognizer, e);\n" +
+			"    }\n" +
+			"  });\n" +
+			"}\n" +
+			"  : t 'x' ;\n" +
+			"t : r r ;\n" +
+			"r : 'a' 'b' ;\n";
+		execParser("T.g4", grammar, "TParser", "TLexer", "s", "x", false);
+		String expecting =
+			"line 1:0 mismatched input 'x' expecting 'a'\n" +
+			"x\n" +
+			"^\n" +
+			"rule stack: [r, t, s]\n" +
+			"expected literals: 'a'\n" +
+			"recovery: resync by consuming until {'x', 'a'}\n" +
+			"line 1:0 mismatched input 'x' expecting 'a'\n" +
+			"x\n" +
+			"^\n" +
+			"rule stack: [r, t, s]\n" +
+			"expected literals: 'a'\n" +
+			"recovery: skip 'x', then resync by consuming until 'x'\n";
+		String result = stderrDuringParse;
+		assertEquals(expecting, result);
+	}
 }
@@ -122,6 +122,24 @@ public class TestParseTrees extends BaseTest {
 		assertEquals(expecting, result);
 	}
 
+	@Test public void testErrorNodeTextMode() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s\n" +
+			"@init {setErrorNodeTextMode(org.antlr.v4.runtime.tree.ErrorNodeImpl.TextMode.MARKER);}\n" +
+			"@after {System.out.println($r.ctx.getText());}\n" +
+			"  : r=a ;\n" +
+			"a : 'x' 'y'\n" +
+			"  ;\n" +
+			"Z : 'z'; \n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "xzy", false);
+		assertEquals("x<error: z>y\n", result);
+
+		grammar = grammar.replace("MARKER", "EMPTY");
+		result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "xzy", false);
+		assertEquals("xy\n", result);
+	}
+
 	@Test public void testNoViableAlt() throws Exception {
 		String grammar =
 			"grammar T;\n" +
@@ -151,4 +169,158 @@ public class TestParseTrees extends BaseTest {
 		String expecting = "(a x z y y !)\n";
 		assertEquals(expecting, result);
 	}
+
+	@Test public void testDumpTokens() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s\n" +
+			"@after {\n" +
+			"  CommonTokenStream tokens = (CommonTokenStream)_input;\n" +
+			"  java.io.StringWriter w = new java.io.StringWriter();\n" +
+			"  try {\n" +
+			"    org.antlr.v4.runtime.misc.DebugDump.dumpTokens(tokens.getTokens(), (Lexer)tokens.getTokenSource(), w);\n" +
+			"    org.antlr.v4.runtime.misc.DebugDump.dumpTokens(tokens.getTokens(), null, w);\n" +
+			"  }\n" +
+			"  catch (java.io.IOException ioe) { }\n" +
+			"  System.out.print(w);\n" +
+			"}\n" +
+			"  : ID+ EOF ;\n" +
+			"ID : [a-z]+ ;\n" +
+			"WS : [ \\t]+ -> channel(HIDDEN) ;\n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "ab \tc", false);
+		String expecting =
+			"[@0,0:1='ab',<1>,1:0]\n" +
+			"[@1,2:3=' \\t',<2>,channel=HIDDEN,1:2]\n" +
+			"[@2,4:4='c',<1>,1:4]\n" +
+			"[@3,5:4='<EOF>',<-1>,1:5]\n" +
+			// without the lexer, the hidden channel is shown by number
+			"[@0,0:1='ab',<1>,1:0]\n" +
+			"[@1,2:3=' \\t',<2>,channel=1,1:2]\n" +
+			"[@2,4:4='c',<1>,1:4]\n" +
+			"[@3,5:4='<EOF>',<-1>,1:5]\n";
+		assertEquals(expecting, result);
+	}
+
+	@Test public void testDumpTree() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s\n" +
+			"@init {setBuildParseTree(true);}\n" +
+			"@after {\n" +
+			"  java.io.StringWriter w = new java.io.StringWriter();\n" +
+			"  try { org.antlr.v4.runtime.misc.DebugDump.dumpTree($r.ctx, this, w); }\n" +
+			"  catch (java.io.IOException ioe) { }\n" +
+			"  System.out.print(w);\n" +
+			"}\n" +
+			"  :r=a ;\n" +
+			"a : 'x' b ;\n" +
+			"b : 'y' ;\n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "xy", false);
+		String expecting =
+			"a\n" +
+			"  x\n" +
+			"  b\n" +
+			"    y\n";
+		assertEquals(expecting, result);
+	}
+
+	@Test public void testToStringTreeIntoWriter() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s\n" +
+			"@init {setBuildParseTree(true);}\n" +
+			"@after {\n" +
+			"  java.io.StringWriter w = new java.io.StringWriter();\n" +
+			"  try { org.antlr.v4.runtime.tree.Trees.toStringTree($r.ctx, java.util.Arrays.asList(getRuleNames()), w); }\n" +
+			"  catch (java.io.IOException ioe) { }\n" +
+			"  System.out.println(w);\n" +
+			"}\n" +
+			"  :r=a ;\n" +
+			"a : 'x' b ;\n" +
+			"b : 'y' ;\n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "xy", false);
+		String expecting = "(a x (b y))\n";
+		assertEquals(expecting, result);
+	}
+
+	@Test public void testFoldingRangesAndOutline() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s\n" +
+			"@after {\n" +
+			"  java.util.Set<Integer> folds = new java.util.HashSet<Integer>(java.util.Arrays.asList(RULE_cls));\n" +
+			"  System.out.println(Trees.getFoldingRanges(_localctx, folds));\n" +
+			"  java.util.Set<Integer> rules = new java.util.HashSet<Integer>(java.util.Arrays.asList(RULE_cls, RULE_method));\n" +
+			"  for (OutlineNode n : Trees.getOutline(_localctx, rules, ID)) {\n" +
+			"    System.out.println(n.toString(java.util.Arrays.asList(getRuleNames())));\n" +
+			"  }\n" +
+			"}\n" +
+			"  : cls+ EOF ;\n" +
+			"cls : 'class' ID '{' member* '}' ;\n" +
+			"member : method | cls ;\n" +
+			"method : 'def' ID ';' ;\n" +
+			"ID : [a-z]+ ;\n" +
+			"WS : [ \\t\\r\\n]+ -> skip ;\n";
+		String input =
+			"class a {\n" +
+			" def f;\n" +
+			" class b {\n" +
+			"  def g;\n" +
+			" }\n" +
+			"}\n" +
+			"class c { }";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s", input, false);
+		String expecting =
+			"[1..6, 3..5]\n" +
+			"cls a [method f, cls b [method g]]\n" +
+			"cls c\n";
+		assertEquals(expecting, result);
+	}
+
+	@Test public void testRootContextAfterBail() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s : {\n" +
+			"  setErrorHandler(new BailErrorStrategy());\n" +
+			"  try { a(); }\n" +
+			"  catch (org.antlr.v4.runtime.misc.ParseCancellationException e) {\n" +
+			"    System.out.println(getRootContext().toStringTree(this));\n" +
+			"    System.out.println(getAbortException()==e);\n" +
+			"    System.out.println(getReportedErrors());\n" +
+			"  }\n" +
+			"} ;\n" +
+			"a : 'x' b ;\n" +
+			"b : 'y' 'z' ;\n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "xyy", false);
+		String expecting =
+			"(s (a x (b y)))\n" +
+			"true\n" +
+			"[]\n";
+		assertEquals(expecting, result);
+	}
+
+	/** Errors reported before a cancelled parse are kept with the abort. */
+	@Test public void testReportedErrorsAfterCancel() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s : {\n" +
+			"  ((DefaultErrorStrategy)_errHandler).setMaxRecoveriesWithoutProgress(2);\n" +
+			"  setRecordErrors(true);\n" +
+			"  try { a(); }\n" +
+			"  catch (org.antlr.v4.runtime.misc.ParseCancellationException e) {\n" +
+			"    System.out.println(getAbortException()==e);\n" +
+			"    for (ReportedError r : getReportedErrors()) {\n" +
+			"      System.out.println(r.offendingToken.getType()+\" \"+r);\n" +
+			"    }\n" +
+			"  }\n" +
+			"} ;\n" +
+			"a : r r r r EOF ;\n" +
+			"r : 'a' ;\n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "", false);
+		String expecting =
+			"true\n" +
+			"-1 line 1:0 mismatched input '<EOF>' expecting 'a'\n" +
+			"-1 line 1:0 error recovery made no progress in rule r at '<EOF>'\n";
+		assertEquals(expecting, result);
+	}
 }
@@ -307,4 +307,70 @@ public class TestParserExec extends BaseTest {
 		assertNull(this.stderrDuringParse);
 	}
 
+	@Test public void testContextSuperClass() {
+		String grammar =
+			"grammar T;\n" +
+			"options {contextSuperClass=MyCtx;}\n" +
+			"@members {\n" +
+			"public static class MyCtx extends ParserRuleContext {\n" +
+			"  public MyCtx() { }\n" +
+			"  public MyCtx(ParserRuleContext parent, int invokingState) { super(parent, invokingState); }\n" +
+			"}\n" +
+			"}\n" +
+			"s : a {System.out.println($a.ctx instanceof MyCtx);} ;\n" +
+			"a : ID # Id ;\n" +
+			"ID : 'a'..'z'+ ;\n" +
+			"WS : (' '|'\\t'|'\\n')+ -> skip ;\n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s",
+								   "abc", false);
+		assertEquals("true\n", result);
+		assertNull(this.stderrDuringParse);
+	}
+
+	/** Rule a's optional block is not LL(1) because of t, so it is
+	 *  predicted with the ATN; once t and u are pruned, prediction must
+	 *  not follow a's stop state back into them.
+	 */
+	@Test public void testParseAfterPruningUnreachableRules() {
+		String grammar =
+			"grammar T;\n" +
+			"s\n" +
+			"@init {setBuildParseTree(true); pruneUnreachableRules(RULE_s);}\n" +
+			"@after {\n" +
+			"  System.out.println(_localctx.toStringTree(this));\n" +
+			"  System.out.println(getATN().ruleToStartState[RULE_t]!=null);\n" +
+			"}\n" +
+			"  : a ;\n" +
+			"a : A (B C | ) ;\n" +
+			"t : a u ;\n" +
+			"u : B ;\n" +
+			"A : 'a' ;\n" +
+			"B : 'b' ;\n" +
+			"C : 'c' ;\n" +
+			"WS : (' '|'\\n')+ -> skip ;\n";
+		String result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "a b c", false);
+		// the shared ATN still has t; only this parser's copy was pruned
+		assertEquals("(s (a a b c))\ntrue\n", result);
+		result = execParser("T.g4", grammar, "TParser", "TLexer", "s", "a", false);
+		assertEquals("(s (a a))\ntrue\n", result);
+		assertNull(this.stderrDuringParse);
+	}
+
+	@Test public void testGrammarFingerprint() {
+		String grammar =
+			"grammar T;\n" +
+			"s : ID {System.out.println(getGrammarFingerprint());} ;\n" +
+			"ID : 'a'..'z'+ ;\n" +
+			"WS : (' '|'\\t'|'\\n')+ -> skip ;\n";
+		String first = execParser("T.g4", grammar, "TParser", "TLexer", "s", "abc", false);
+		assertEquals(41, first.length()); // 40 hex digits and newline
+		String again = execParser("T.g4", grammar, "TParser", "TLexer", "s", "abc", false);
+		assertEquals(first, again);
+
+		grammar = grammar.replace("s : ID {", "s : ID ID? {");
+		String changed = execParser("T.g4", grammar, "TParser", "TLexer", "s", "abc", false);
+		assertFalse(first.equals(changed));
+		assertNull(this.stderrDuringParse);
+	}
+
 }
@@ -0,0 +1,135 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+package org.antlr.v4.test;
+
+import org.antlr.v4.automata.ATNSerializer;
+import org.antlr.v4.runtime.ANTLRInputStream;
+import org.antlr.v4.runtime.CommonTokenStream;
+import org.antlr.v4.runtime.InterpreterRuleContext;
+import org.antlr.v4.runtime.LexerInterpreter;
+import org.antlr.v4.runtime.ParserInterpreter;
+import org.antlr.v4.runtime.ParserRuleContext;
+import org.antlr.v4.runtime.RuleContext;
+import org.antlr.v4.runtime.atn.ATN;
+import org.antlr.v4.runtime.atn.ATNSimulator;
+import org.antlr.v4.tool.Grammar;
+import org.antlr.v4.tool.LexerGrammar;
+import org.junit.Test;
+
+import static org.junit.Assert.*;
+
+/** Tests for the runtime {@link ParserInterpreter}, which parses from a
+ *  deserialized ATN without a generated parser.
+ */
+public class TestParserInterpreter extends BaseTest {
+	@Test public void testRuntimeParserInterpreter() throws Exception {
+		Grammar g = new Grammar(
+			"grammar T;\n" +
+			"s : e ;\n" +
+			"e : e '*' e\n" +
+			"  | e '+' e\n" +
+			"  | INT\n" +
+			"  ;\n" +
+			"INT : [0-9]+ ;\n");
+		g.tool.process(g, false);
+		LexerGrammar lg = g.implicitLexer;
+		LexerInterpreter lexer =
+			new LexerInterpreter("T.g4", createATN(lg, true), lg.getTokenDisplayNames(),
+								 lg.rules.keySet().toArray(new String[0]),
+								 lg.modes.keySet().toArray(new String[0]),
+								 null, new ANTLRInputStream("1+2*3+4"));
+		ATN atn = ATNSimulator.deserialize(ATNSerializer.getSerializedAsChars(g, g.atn));
+		ParserInterpreter parser =
+			new ParserInterpreter("T.g4", atn, g.getTokenDisplayNames(),
+								  g.rules.keySet().toArray(new String[0]),
+								  new CommonTokenStream(lexer));
+		ParserRuleContext tree = parser.parse(g.getRule("s").index);
+		assertEquals("(s (e (e (e 1) + (e (e 2) * (e 3))) + (e 4)))", tree.toStringTree(parser));
+		assertTrue(tree instanceof InterpreterRuleContext);
+	}
+
+	@Test public void testRuntimeParserInterpreterFingerprint() throws Exception {
+		Grammar g = new Grammar(
+			"parser grammar T;\n" +
+			"tokens {A}\n" +
+			"s : A ;");
+		String serializedATN = new String(ATNSerializer.getSerializedAsChars(g, createATN(g, false)));
+		String[] ruleNames = g.rules.keySet().toArray(new String[0]);
+		ParserInterpreter parser =
+			new ParserInterpreter("T.g4", serializedATN, g.getTokenDisplayNames(),
+								  ruleNames, null);
+		assertEquals(serializedATN, parser.getSerializedATN());
+		String fingerprint = parser.getGrammarFingerprint();
+		assertEquals(40, fingerprint.length());
+		parser = new ParserInterpreter("T.g4", serializedATN, g.getTokenDisplayNames(),
+									   ruleNames, null);
+		assertEquals(fingerprint, parser.getGrammarFingerprint());
+	}
+
+	@Test public void testRuntimeParserInterpreterSempred() throws Exception {
+		Grammar g = new Grammar(
+			"grammar T;\n" +
+			"s : x | y ;\n" +
+			"x : {p}? A ;\n" +
+			"y : A ;\n" +
+			"A : 'a' ;\n");
+		g.tool.process(g, false);
+		ATN atn = ATNSimulator.deserialize(ATNSerializer.getSerializedAsChars(g, g.atn));
+		String[] ruleNames = g.rules.keySet().toArray(new String[0]);
+
+		// predicates are true unless sempred is overridden
+		ParserInterpreter parser =
+			new ParserInterpreter("T.g4", atn, g.getTokenDisplayNames(), ruleNames,
+								  new CommonTokenStream(createLexer(g, "a")));
+		ParserRuleContext tree = parser.parse(g.getRule("s").index);
+		assertEquals("(s (x a))", tree.toStringTree(parser));
+
+		parser =
+			new ParserInterpreter("T.g4", atn, g.getTokenDisplayNames(), ruleNames,
+								  new CommonTokenStream(createLexer(g, "a")))
+			{
+				@Override
+				public boolean sempred(RuleContext _localctx, int ruleIndex, int predIndex) {
+					return false;
+				}
+			};
+		tree = parser.parse(g.getRule("s").index);
+		assertEquals("(s (y a))", tree.toStringTree(parser));
+	}
+
+	protected LexerInterpreter createLexer(Grammar g, String input) {
+		LexerGrammar lg = g.implicitLexer;
+		return new LexerInterpreter("T.g4", createATN(lg, true), lg.getTokenDisplayNames(),
+									lg.rules.keySet().toArray(new String[0]),
+									lg.modes.keySet().toArray(new String[0]),
+									null, new ANTLRInputStream(input));
+	}
+}
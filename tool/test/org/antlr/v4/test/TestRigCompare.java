@@ -0,0 +1,83 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.test;
+
+import org.antlr.v4.runtime.misc.TestRig;
+import org.junit.Test;
+
+import java.util.Arrays;
+import java.util.Collections;
+
+import static org.junit.Assert.assertEquals;
+
+public class TestRigCompare extends BaseTest {
+	@Test public void testSameOutput() {
+		String[] lines = {"(s a)", "(s b)"};
+		assertEquals(Collections.<String>emptyList(), TestRig.diff(lines, lines.clone()));
+	}
+
+	@Test public void testChangedLine() {
+		assertEquals(Arrays.asList("line 2:", "- b", "+ B"),
+					 TestRig.diff(new String[] {"a", "b", "c"},
+								  new String[] {"a", "B", "c"}));
+	}
+
+	/** An extra line is reported once; the lines after it still match. */
+	@Test public void testInsertedAndMissingLines() {
+		assertEquals(Arrays.asList("line 2:", "+ x", "line 4:", "- d"),
+					 TestRig.diff(new String[] {"a", "b", "c", "d"},
+								  new String[] {"a", "x", "b", "c"}));
+	}
+
+	/** Only the differing middle is aligned, so long outputs are cheap. */
+	@Test public void testLargeOutputWithOneChangedLine() {
+		String[] expected = new String[100000];
+		String[] found = new String[expected.length];
+		for (int i = 0; i < expected.length; i++) {
+			expected[i] = "[@"+i+"]";
+			found[i] = expected[i];
+		}
+		found[50000] = "changed";
+		assertEquals(Arrays.asList("line 50001:", "- [@50000]", "+ changed"),
+					 TestRig.diff(expected, found));
+	}
+
+	@Test public void testTooManyDifferencesToAlign() {
+		String[] expected = new String[100000];
+		String[] found = new String[expected.length];
+		for (int i = 0; i < expected.length; i++) {
+			expected[i] = "e"+i;
+			found[i] = "f"+i;
+		}
+		assertEquals(Arrays.asList("line 1:", "- e0", "+ f0",
+								   "(too many differences to align; only the first is shown)"),
+					 TestRig.diff(expected, found));
+	}
+}
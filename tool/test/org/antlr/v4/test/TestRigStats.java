@@ -0,0 +1,79 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+package org.antlr.v4.test;
+
+import org.junit.Test;
+
+import static org.junit.Assert.*;
+
+public class TestRigStats extends BaseTest {
+	/** Decision 0 in s needs two tokens; decision 1 is the operator loop
+	 *  of left-recursive e, whose DFA has a start state per precedence.
+	 */
+	@Test public void testStats() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"s : (ID '=' e | ID ':' e) EOF ;\n" +
+			"e : e '*' e | INT ;\n" +
+			"ID : [a-z]+ ;\n" +
+			"INT : [0-9]+ ;\n";
+		String stats = execStats(grammar, "a=1*2*3");
+		String[] lines = stats.split("\n");
+		assertEquals("lexer: 5 rules, 1 modes", lines[0]);
+		assertTrue(lines[1], lines[1].matches("  mode 0: \\d+ DFA states"));
+		assertEquals("parser: 2 rules, 2 decisions", lines[2]);
+		assertEquals("  decision 0 (s): 3 DFA states, depth 2", lines[3]);
+		assertTrue(lines[4], lines[4].matches("  decision 1 \\(e\\): \\d+ DFA states, depth 1"));
+		assertEquals(5, lines.length);
+	}
+
+	/** Run TestRig -stats on the generated recognizer for grammar T and
+	 *  return what it printed to stderr.
+	 */
+	protected String execStats(String grammar, String input) {
+		assertTrue(rawGenerateAndBuildRecognizer("T.g4", grammar, "TParser", "TLexer"));
+		writeFile(tmpdir, "Stats.java",
+			"import org.antlr.v4.runtime.misc.TestRig;\n" +
+			"\n" +
+			"public class Stats {\n" +
+			"	public static void main(String[] args) throws Exception {\n" +
+			"		// TestRig loads the recognizers through the context class loader\n" +
+			"		Thread.currentThread().setContextClassLoader(Stats.class.getClassLoader());\n" +
+			"		TestRig.main(new String[] {\"T\", \"s\", \"-stats\", args[0]});\n" +
+			"	}\n" +
+			"}\n");
+		assertTrue(compile("Stats.java"));
+		writeFile(tmpdir, "input", input);
+		assertEquals("", execClass("Stats"));
+		assertNotNull(stderrDuringParse);
+		return stderrDuringParse;
+	}
+}
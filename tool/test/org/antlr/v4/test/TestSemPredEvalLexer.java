@@ -180,4 +180,34 @@ public class TestSemPredEvalLexer extends BaseTest {
 			"[@3,10:9='<EOF>',<-1>,1:10]\n";
 		assertEquals(expecting, found);
 	}
+
+	@Test public void testSpeculateInPredicate() {
+		String grammar =
+			"lexer grammar A;\n" +
+			"@members {\n" +
+			"boolean followedBy(final char c) {\n" +
+			"  return speculate(new Speculation() {\n" +
+			"    public boolean matches(CharStream input) {\n" +
+			"      while ( input.LA(1)==' ' ) input.consume();\n" +
+			"      return input.LA(1)==c;\n" +
+			"    }\n" +
+			"  });\n" +
+			"}\n" +
+			"}\n" +
+			"KW : 'if' {followedBy('(')}? ;\n" +
+			"ID : [a-z]+ ;\n" +
+			"LP : '(' ;\n" +
+			"RP : ')' ;\n" +
+			"WS : ' '+ -> skip ;";
+		String found = execLexer("A.g4", grammar, "A", "if (x) if x");
+		String expecting =
+			"[@0,0:1='if',<1>,1:0]\n" +
+			"[@1,3:3='(',<3>,1:3]\n" +
+			"[@2,4:4='x',<2>,1:4]\n" +
+			"[@3,5:5=')',<4>,1:5]\n" +
+			"[@4,7:8='if',<2>,1:7]\n" +
+			"[@5,10:10='x',<2>,1:10]\n" +
+			"[@6,11:10='<EOF>',<-1>,1:11]\n";
+		assertEquals(expecting, found);
+	}
 }
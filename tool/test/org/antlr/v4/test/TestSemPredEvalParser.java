@@ -548,4 +548,29 @@ public class TestSemPredEvalParser extends BaseTest {
 		assertEquals("line 1:0 no viable alternative at input 'enum'\n", stderrDuringParse);
 	}
 
+	/** Predicates at the left edge of a left-recursive rule's primary
+	 *  alternatives are collected by the closure of the primary decision
+	 *  and resolve the SLL conflict between INT followed by the operator
+	 *  loop and INT '+' INT. A predicate after the first INT would not be
+	 *  visible to the decision at all.
+	 */
+	@Test public void testPredsInLeftRecursivePrimaryAlts() throws Exception {
+		String grammar =
+			"grammar T;\n" +
+			"@members {boolean flat = false;}\n" +
+			"s @after {System.out.println($ctx.toStringTree(this));}\n" +
+			"  : e ';' {flat = true;} e ';' EOF ;\n" +
+			"e : e '+' e\n" +
+			"  | {!flat}? INT\n" +
+			"  | {flat}? INT '+' INT\n" +
+			"  ;\n" +
+			"INT : '0'..'9'+;\n" +
+			"WS : (' '|'\\n') {skip();} ;\n";
+
+		String found = execParser("T.g4", grammar, "TParser", "TLexer", "s",
+								  "1+2; 3+4;", false);
+		assertEquals("(s (e (e 1) + (e 2)) ; (e 3 + 4) ; <EOF>)\n", found);
+		assertNull(stderrDuringParse);
+	}
+
 }
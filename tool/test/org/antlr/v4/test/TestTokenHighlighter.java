@@ -0,0 +1,70 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.test;
+
+import org.antlr.v4.runtime.ANTLRInputStream;
+import org.antlr.v4.runtime.LexerInterpreter;
+import org.antlr.v4.runtime.misc.TokenHighlighter;
+import org.antlr.v4.tool.LexerGrammar;
+import org.junit.Test;
+
+import java.util.List;
+
+import static org.junit.Assert.assertEquals;
+
+public class TestTokenHighlighter extends BaseTest {
+	@Test public void testHighlightAndRehighlight() throws Exception {
+		LexerGrammar lg = new LexerGrammar(
+			"lexer grammar L;\n"+
+			"IF : 'if' ;\n"+
+			"PLUS : '+' ;\n"+
+			"ID : [a-z]+ ;\n"+
+			"WS : ' '+ ;\n");
+		LexerInterpreter lexer =
+			new LexerInterpreter("L.g4", createATN(lg, true), lg.getTokenDisplayNames(),
+								 lg.rules.keySet().toArray(new String[0]),
+								 lg.modes.keySet().toArray(new String[0]),
+								 null, new ANTLRInputStream(""));
+		TokenHighlighter highlighter = new TokenHighlighter(lexer);
+		highlighter.setTokenStyle(lg.getTokenType("WS"), "space");
+
+		List<TokenHighlighter.Span> spans = highlighter.highlight(new ANTLRInputStream("if a + bb"));
+		assertEquals("[0..1:keyword, 2..2:space, 3..3:ID, 4..4:space, 5..5:operator, 6..6:space, 7..8:ID]",
+					 spans.toString());
+
+		// insert "c" after "a"
+		ANTLRInputStream edited = new ANTLRInputStream("if ac + bb");
+		List<TokenHighlighter.Span> updated = highlighter.rehighlight(spans, edited, 4, 0, 1);
+		assertEquals("[0..1:keyword, 2..2:space, 3..4:ID, 5..5:space, 6..6:operator, 7..7:space, 8..9:ID]",
+					 updated.toString());
+		assertEquals(highlighter.highlight(new ANTLRInputStream("if ac + bb")).toString(),
+					 updated.toString());
+	}
+}
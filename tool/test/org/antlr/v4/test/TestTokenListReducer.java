@@ -0,0 +1,84 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.test;
+
+import org.antlr.v4.runtime.CommonToken;
+import org.antlr.v4.runtime.Token;
+import org.antlr.v4.runtime.misc.TokenListReducer;
+import org.junit.Test;
+
+import java.util.ArrayList;
+import java.util.List;
+
+import static org.junit.Assert.assertEquals;
+
+public class TestTokenListReducer extends BaseTest {
+	/** Fails whenever every one of the given texts appears, in any order. */
+	static class ContainsAll implements TokenListReducer.Failure {
+		final String[] texts;
+		ContainsAll(String... texts) { this.texts = texts; }
+		@Override
+		public boolean reproduces(List<? extends Token> tokens) {
+			for (String text : texts) {
+				boolean found = false;
+				for (Token t : tokens) found |= t.getText().equals(text);
+				if ( !found ) return false;
+			}
+			return true;
+		}
+	}
+
+	@Test public void testReduceToFailingTokens() {
+		List<Token> tokens = new ArrayList<Token>();
+		for (char c='a'; c<='z'; c++) tokens.add(new CommonToken(1, String.valueOf(c)));
+		TokenListReducer reducer = new TokenListReducer(new ContainsAll("c", "q", "z"));
+		assertEquals("cqz", getText(reducer.reduce(tokens)));
+	}
+
+	@Test public void testReduceToSingleToken() {
+		List<Token> tokens = new ArrayList<Token>();
+		for (char c='a'; c<='e'; c++) tokens.add(new CommonToken(1, String.valueOf(c)));
+		TokenListReducer reducer = new TokenListReducer(new ContainsAll("e"));
+		assertEquals("e", getText(reducer.reduce(tokens)));
+	}
+
+	@Test(expected=IllegalArgumentException.class)
+	public void testInputMustFail() {
+		List<Token> tokens = new ArrayList<Token>();
+		tokens.add(new CommonToken(1, "a"));
+		new TokenListReducer(new ContainsAll("b")).reduce(tokens);
+	}
+
+	static String getText(List<? extends Token> tokens) {
+		StringBuilder buf = new StringBuilder();
+		for (Token t : tokens) buf.append(t.getText());
+		return buf.toString();
+	}
+}
@@ -0,0 +1,84 @@
+/*
+ * [The "BSD license"]
+ *  Copyright (c) 2012 Terence Parr
+ *  Copyright (c) 2012 Sam Harwell
+ *  All rights reserved.
+ *
+ *  Redistribution and use in source and binary forms, with or without
+ *  modification, are permitted provided that the following conditions
+ *  are met:
+ *
+ *  1. Redistributions of source code must retain the above copyright
+ *     notice, this list of conditions and the following disclaimer.
+ *  2. Redistributions in binary form must reproduce the above copyright
+ *     notice, this list of conditions and the following disclaimer in the
+ *     documentation and/or other materials provided with the distribution.
+ *  3. The name of the author may not be used to endorse or promote products
+ *     derived from this software without specific prior written permission.
+ *
+ *  THIS SOFTWARE IS PROVIDED BY THE AUTHOR ``AS IS'' AND ANY EXPRESS OR
+ *  IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES
+ *  OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED.
+ *  IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY DIRECT, INDIRECT,
+ *  INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT
+ *  NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE,
+ *  DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY
+ *  THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT
+ *  (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF
+ *  THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+package org.antlr.v4.test;
+
+import org.antlr.v4.runtime.Token;
+import org.antlr.v4.runtime.Vocabulary;
+import org.antlr.v4.runtime.misc.TokenVocabulary;
+import org.junit.Test;
+
+import java.io.StringReader;
+import java.util.Arrays;
+
+import static org.junit.Assert.assertEquals;
+import static org.junit.Assert.assertNull;
+import static org.junit.Assert.fail;
+
+public class TestTokenVocabulary extends BaseTest {
+	@Test public void testLoad() throws Exception {
+		TokenVocabulary vocab = TokenVocabulary.load(new StringReader(
+			"T__0=1\n" +
+			"ID=2\n" +
+			"WS=3\n" +
+			"\n" +
+			"EQ=4\n" +
+			"'='=4\n" +
+			"'+'=1\n" +
+			"'=='=5\n"), "T.tokens");
+		assertEquals(5, vocab.getMaxTokenType());
+		assertEquals("[<INVALID>, '+', ID, WS, EQ, '==']", Arrays.toString(vocab.getTokenNames()));
+		assertEquals(4, vocab.getTokenType("EQ"));
+		assertEquals(4, vocab.getTokenType("'='"));
+		assertEquals(Token.INVALID_TYPE, vocab.getTokenType("INT"));
+		assertEquals("'='", vocab.getLiteralName(4));
+		assertNull(vocab.getSymbolicName(5));
+	}
+
+	@Test public void testEmptyVocabulary() throws Exception {
+		Vocabulary vocab = TokenVocabulary.EMPTY_VOCABULARY;
+		assertEquals(0, vocab.getMaxTokenType());
+		assertEquals("EOF", vocab.getSymbolicName(Token.EOF));
+		assertEquals("EOF", vocab.getDisplayName(Token.EOF));
+		assertNull(vocab.getLiteralName(1));
+		assertEquals("1", vocab.getDisplayName(1));
+		// nothing can add token types to the shared constant
+		assertEquals(0, TokenVocabulary.class.getConstructors().length);
+	}
+
+	@Test public void testBadLine() throws Exception {
+		try {
+			TokenVocabulary.load(new StringReader("ID=2\nWS 3\n"), "T.tokens");
+			fail("expected IllegalArgumentException");
+		}
+		catch (IllegalArgumentException e) {
+			assertEquals("T.tokens:2: bad token definition: WS 3", e.getMessage());
+		}
+	}
+}
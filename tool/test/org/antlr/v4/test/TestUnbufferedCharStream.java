@@ -30,6 +30,7 @@
 
 package org.antlr.v4.test;
 
+import org.antlr.v4.runtime.ANTLRInputStream;
 import org.antlr.v4.runtime.CharStream;
 import org.antlr.v4.runtime.CommonTokenFactory;
 import org.antlr.v4.runtime.CommonTokenStream;
@@ -162,6 +163,20 @@ public class TestUnbufferedCharStream extends BaseTest {
 		assertEquals("yz", input.getText(new Interval(1, 2)));
 	}
 
+	@Test
+	public void testGetTextPastEOFIsClamped() {
+		CharStream[] inputs = {createStream("xyz"), new ANTLRInputStream("xyz")};
+		for (CharStream input : inputs) {
+			int m1 = input.mark();
+			input.seek(10);
+			assertEquals(3, input.index());
+			assertEquals(IntStream.EOF, input.LA(1));
+			assertEquals("yz", input.getText(new Interval(1, 10)));
+			assertEquals("", input.getText(new Interval(3, 10)));
+			input.release(m1);
+		}
+	}
+
 	@Test
 	public void testLastChar() {
 		CharStream input = createStream("abcdef");
@@ -330,6 +345,62 @@ public class TestUnbufferedCharStream extends BaseTest {
 		assertEquals(expecting, tokens.getTokens().toString());
     }
 
+	/**
+	 * The lexer scans past its last accept state looking for a longer match
+	 * and must seek back once that fails. The characters after the accept
+	 * point were read into the buffer while a mark was held, so they must
+	 * still be available after the rollback even though the buffer started
+	 * out much smaller than the scanned prefix.
+	 */
+	@Test public void testRollbackAfterLongAmbiguousPrefix() throws Exception {
+		LexerGrammar g = new LexerGrammar(
+			"lexer grammar t;\n"+
+			"KEYWORD : 'abcdefgh';\n" +
+			"ID : 'a'..'z';\n" +
+			"WS : ' '+;\n");
+		TestingUnbufferedCharStream input = createStream("abcdefgx abcdefgh abcd", 2);
+		LexerInterpreter lexEngine = new LexerInterpreter(g);
+		lexEngine.setTokenFactory(new CommonTokenFactory(true));
+		lexEngine.setInput(input);
+		CommonTokenStream tokens = new CommonTokenStream(lexEngine);
+		tokens.fill();
+		String expecting =
+			"[[@0,0:0='a',<2>,1:0], [@1,1:1='b',<2>,1:1], [@2,2:2='c',<2>,1:2]," +
+			" [@3,3:3='d',<2>,1:3], [@4,4:4='e',<2>,1:4], [@5,5:5='f',<2>,1:5]," +
+			" [@6,6:6='g',<2>,1:6], [@7,7:7='x',<2>,1:7], [@8,8:8=' ',<3>,1:8]," +
+			" [@9,9:16='abcdefgh',<1>,1:9], [@10,17:17=' ',<3>,1:17]," +
+			" [@11,18:18='a',<2>,1:18], [@12,19:19='b',<2>,1:19]," +
+			" [@13,20:20='c',<2>,1:20], [@14,21:21='d',<2>,1:21]," +
+			" [@15,22:21='',<-1>,1:22]]";
+		assertEquals(expecting, tokens.getTokens().toString());
+		// all marks were released, so nothing before the EOF is retained
+		assertEquals("\uFFFF", input.getBuffer());
+	}
+
+	/**
+	 * Same as {@link #testRollbackAfterLongAmbiguousPrefix}, but the failed
+	 * speculative scan also spans a line break, so the line and column of the
+	 * rollback position must be restored along with the index.
+	 */
+	@Test public void testRollbackRestoresLineAndColumn() throws Exception {
+		LexerGrammar g = new LexerGrammar(
+			"lexer grammar t;\n"+
+			"COMMENT : '/*' .*? '*/';\n" +
+			"SLASH : '/';\n" +
+			"STAR : '*';\n" +
+			"NL : '\\n';\n");
+		TestingUnbufferedCharStream input = createStream("/*\n*", 1);
+		LexerInterpreter lexEngine = new LexerInterpreter(g);
+		lexEngine.setTokenFactory(new CommonTokenFactory(true));
+		lexEngine.setInput(input);
+		CommonTokenStream tokens = new CommonTokenStream(lexEngine);
+		tokens.fill();
+		String expecting =
+			"[[@0,0:0='/',<2>,1:0], [@1,1:1='*',<3>,1:1], [@2,2:2='\\n',<4>,1:2]," +
+			" [@3,3:3='*',<3>,2:0], [@4,4:3='',<-1>,2:1]]";
+		assertEquals(expecting, tokens.getTokens().toString());
+	}
+
 	protected static TestingUnbufferedCharStream createStream(String text) {
 		return new TestingUnbufferedCharStream(new StringReader(text));
 	}